@@ -3,13 +3,21 @@
 //! 该模块提供了与 Bitget 账户相关的 API 接口
 //! 包括查询账户信息、账户资产等功能
 
-use anyhow::Result;
+use crate::exceptions::Result;
 use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
 use tracing::debug;
 
 use crate::client::BitgetClient;
 use crate::consts;
+use crate::rate_limit::EndpointLimit;
+
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[
+    EndpointLimit::uid("/api/mix/v1/account/account", 20),
+    EndpointLimit::uid("/api/mix/v1/position/allPosition", 5),
+    EndpointLimit::uid("/api/mix/v1/account/setLeverage", 5),
+];
 
 /// 账户信息响应结构体
 #[derive(Debug, Clone, Deserialize, Serialize)]
@@ -55,6 +63,7 @@ pub struct PositionInfoResp {
     pub frozen: Option<String>,
 
     /// 持仓方向（1: 多头方向，2: 空头方向）
+    #[serde(rename = "holdSide")]
     pub hold_side: Option<String>,
 
     /// 持仓模式（1: 逻辑仓位，2: 真实仓位）
@@ -69,6 +78,29 @@ pub struct PositionInfoResp {
     pub leverage: Option<String>,
 }
 
+/// 设置杠杆响应结构体
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SetLeverageResp {
+    /// 交易对
+    pub symbol: Option<String>,
+
+    /// 保证金币种
+    pub margin_coin: Option<String>,
+
+    /// 多仓杠杆
+    pub long_leverage: Option<String>,
+
+    /// 空仓杠杆
+    pub short_leverage: Option<String>,
+
+    /// 全仓杠杆
+    pub cross_margin_leverage: Option<String>,
+
+    /// 保证金模式
+    pub margin_mode: Option<String>,
+}
+
 impl BitgetClient {
     /// 查询账户信息
     ///
@@ -77,8 +109,8 @@ impl BitgetClient {
     /// * `margin_coin` - 保证金币种
     ///
     /// # 返回
-    /// 返回账户信息响应字符串或错误
-    pub fn get_account_info(&self, symbol: &str, margin_coin: &str) -> Result<String> {
+    /// 返回账户信息或错误
+    pub fn get_account_info(&self, symbol: &str, margin_coin: &str) -> Result<AccountInfoResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("marginCoin".to_string(), margin_coin.to_string());
@@ -88,7 +120,7 @@ impl BitgetClient {
             symbol, margin_coin
         );
 
-        self.request(consts::GET, "/api/mix/v1/account/account", &params, false)
+        self.request_typed(consts::GET, "/api/mix/v1/account/account", &params)
     }
 
     /// 查询账户信息（异步版本）
@@ -98,8 +130,12 @@ impl BitgetClient {
     /// * `margin_coin` - 保证金币种
     ///
     /// # 返回
-    /// 返回账户信息响应字符串或错误
-    pub async fn get_account_info_async(&self, symbol: &str, margin_coin: &str) -> Result<String> {
+    /// 返回账户信息或错误
+    pub async fn get_account_info_async(
+        &self,
+        symbol: &str,
+        margin_coin: &str,
+    ) -> Result<AccountInfoResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("marginCoin".to_string(), margin_coin.to_string());
@@ -109,7 +145,8 @@ impl BitgetClient {
             symbol, margin_coin
         );
 
-        self.request(consts::GET, "/api/mix/v1/account/account", &params, false)
+        self.request_typed_async(consts::GET, "/api/mix/v1/account/account", &params)
+            .await
     }
 
     /// 查询持仓信息
@@ -119,8 +156,8 @@ impl BitgetClient {
     /// * `margin_coin` - 保证金币种
     ///
     /// # 返回
-    /// 返回持仓信息响应字符串或错误
-    pub fn get_positions(&self, symbol: &str, margin_coin: &str) -> Result<String> {
+    /// 返回持仓信息列表或错误
+    pub fn get_positions(&self, symbol: &str, margin_coin: &str) -> Result<Vec<PositionInfoResp>> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("marginCoin".to_string(), margin_coin.to_string());
@@ -130,12 +167,7 @@ impl BitgetClient {
             symbol, margin_coin
         );
 
-        self.request(
-            consts::GET,
-            "/api/mix/v1/position/allPosition",
-            &params,
-            false,
-        )
+        self.request_typed(consts::GET, "/api/mix/v1/position/allPosition", &params)
     }
 
     /// 查询持仓信息（异步版本）
@@ -145,8 +177,12 @@ impl BitgetClient {
     /// * `margin_coin` - 保证金币种
     ///
     /// # 返回
-    /// 返回持仓信息响应字符串或错误
-    pub async fn get_positions_async(&self, symbol: &str, margin_coin: &str) -> Result<String> {
+    /// 返回持仓信息列表或错误
+    pub async fn get_positions_async(
+        &self,
+        symbol: &str,
+        margin_coin: &str,
+    ) -> Result<Vec<PositionInfoResp>> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("marginCoin".to_string(), margin_coin.to_string());
@@ -156,12 +192,8 @@ impl BitgetClient {
             symbol, margin_coin
         );
 
-        self.request(
-            consts::GET,
-            "/api/mix/v1/position/allPosition",
-            &params,
-            false,
-        )
+        self.request_typed_async(consts::GET, "/api/mix/v1/position/allPosition", &params)
+            .await
     }
 
     /// 设置杠杆模式
@@ -173,14 +205,14 @@ impl BitgetClient {
     /// * `hold_side` - 持仓方向
     ///
     /// # 返回
-    /// 返回设置结果响应字符串或错误
+    /// 返回设置结果或错误
     pub fn set_leverage(
         &self,
         symbol: &str,
         margin_coin: &str,
         leverage: &str,
         hold_side: &str,
-    ) -> Result<String> {
+    ) -> Result<SetLeverageResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("marginCoin".to_string(), margin_coin.to_string());
@@ -192,12 +224,7 @@ impl BitgetClient {
             symbol, margin_coin, leverage, hold_side
         );
 
-        self.request(
-            consts::POST,
-            "/api/mix/v1/account/setLeverage",
-            &params,
-            false,
-        )
+        self.request_typed(consts::POST, "/api/mix/v1/account/setLeverage", &params)
     }
 
     /// 设置杠杆模式（异步版本）
@@ -209,14 +236,14 @@ impl BitgetClient {
     /// * `hold_side` - 持仓方向
     ///
     /// # 返回
-    /// 返回设置结果响应字符串或错误
+    /// 返回设置结果或错误
     pub async fn set_leverage_async(
         &self,
         symbol: &str,
         margin_coin: &str,
         leverage: &str,
         hold_side: &str,
-    ) -> Result<String> {
+    ) -> Result<SetLeverageResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("marginCoin".to_string(), margin_coin.to_string());
@@ -228,11 +255,7 @@ impl BitgetClient {
             symbol, margin_coin, leverage, hold_side
         );
 
-        self.request(
-            consts::POST,
-            "/api/mix/v1/account/setLeverage",
-            &params,
-            false,
-        )
+        self.request_typed_async(consts::POST, "/api/mix/v1/account/setLeverage", &params)
+            .await
     }
 }
@@ -1,39 +1,321 @@
 //! Bitget 资产相关接口
 //! 示例：GET /api/mix/v1/account/account
 use crate::client::BitgetClient;
-use anyhow::Result;
-use serde::Deserialize;
+use crate::exceptions::Result;
+use crate::pagination::{self, CursorItem, Paginator};
+use crate::rate_limit::EndpointLimit;
+use crate::time_window::{self, TimeRangeQuery};
+use serde::{Deserialize, Serialize};
 use std::collections::BTreeMap;
+use std::time::Duration;
 
-/// 资产信息响应结构体（可根据实际返回补充字段）
-#[derive(Debug, Deserialize)]
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[
+    EndpointLimit::uid("/api/spot/v1/account/assets", 10),
+    EndpointLimit::uid("/api/v2/spot/account/assets", 10),
+    EndpointLimit::uid("/api/v2/spot/wallet/deposit-records", 10),
+    EndpointLimit::uid("/api/v2/spot/wallet/withdrawal-records", 10),
+];
+
+/// 充值 / 提现记录每页最大条数
+pub const WALLET_RECORDS_PAGE_SIZE: u32 = 100;
+
+/// 充值 / 提现记录单次查询的最大时间跨度
+pub const WALLET_RECORDS_MAX_SPAN: Duration = time_window::NINETY_DAYS;
+
+/// 资产信息响应结构体（兼容 v1 / v2 字段）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct AssetInfoResp {
+    #[serde(alias = "coinName")]
     pub coin: Option<String>,
     pub available: Option<String>,
-    // TODO: 按需补充更多字段
+    pub frozen: Option<String>,
+    #[serde(alias = "lock")]
+    pub locked: Option<String>,
+    pub limit_available: Option<String>,
+    pub u_time: Option<String>,
+}
+
+/// 充值记录查询参数（v2）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DepositRecordsQuery {
+    /// 币种
+    pub coin: Option<String>,
+    /// 充值订单 ID
+    pub order_id: Option<String>,
+    /// 开始时间（毫秒时间戳，必填）
+    pub start_time: i64,
+    /// 结束时间（毫秒时间戳，必填）
+    pub end_time: i64,
+    /// 每页条数，最大 100
+    pub limit: Option<u32>,
+    /// 游标：返回 orderId 小于该值的记录
+    pub id_less_than: Option<String>,
+}
+
+/// 提现记录查询参数（v2）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WithdrawalRecordsQuery {
+    /// 币种
+    pub coin: Option<String>,
+    /// 提现订单 ID
+    pub order_id: Option<String>,
+    /// 自定义订单 ID
+    pub client_oid: Option<String>,
+    /// 开始时间（毫秒时间戳，必填）
+    pub start_time: i64,
+    /// 结束时间（毫秒时间戳，必填）
+    pub end_time: i64,
+    /// 每页条数，最大 100
+    pub limit: Option<u32>,
+    /// 游标：返回 orderId 小于该值的记录
+    pub id_less_than: Option<String>,
+}
+
+/// 充值 / 提现记录（v2）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WalletRecordResp {
+    pub order_id: Option<String>,
+    pub trade_id: Option<String>,
+    pub client_oid: Option<String>,
+    pub coin: Option<String>,
+    #[serde(rename = "type")]
+    pub record_type: Option<String>,
+    pub size: Option<String>,
+    pub fee: Option<String>,
+    pub status: Option<String>,
+    pub from_address: Option<String>,
+    pub to_address: Option<String>,
+    pub chain: Option<String>,
+    pub dest: Option<String>,
+    pub tag: Option<String>,
+    pub confirm: Option<String>,
+    pub c_time: Option<String>,
+    pub u_time: Option<String>,
+}
+
+impl CursorItem for WalletRecordResp {
+    fn cursor_id(&self) -> Option<&str> {
+        self.order_id.as_deref()
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        pagination::parse_ms(&self.c_time)
+    }
+}
+
+impl TimeRangeQuery for DepositRecordsQuery {
+    fn set_time_range(&mut self, start_ms: i64, end_ms: i64) {
+        self.start_time = start_ms;
+        self.end_time = end_ms;
+        self.id_less_than = None;
+    }
+}
+
+impl TimeRangeQuery for WithdrawalRecordsQuery {
+    fn set_time_range(&mut self, start_ms: i64, end_ms: i64) {
+        self.start_time = start_ms;
+        self.end_time = end_ms;
+        self.id_less_than = None;
+    }
 }
 
 impl BitgetClient {
     /// 查询资产信息（v1，已废弃）
-    pub fn get_assets(&self) -> Result<String> {
-        let params = BTreeMap::new();
-        self.request(
-            crate::consts::GET,
-            "/api/spot/v1/account/assets",
-            &params,
-            false,
-        )
+    pub fn get_assets(&self) -> Result<Vec<AssetInfoResp>> {
+        let params: BTreeMap<String, String> = BTreeMap::new();
+        self.request_typed(crate::consts::GET, "/api/spot/v1/account/assets", &params)
+    }
+
+    /// 查询资产信息（v1，已废弃，异步版本）
+    pub async fn get_assets_async(&self) -> Result<Vec<AssetInfoResp>> {
+        let params: BTreeMap<String, String> = BTreeMap::new();
+        self.request_typed_async(crate::consts::GET, "/api/spot/v1/account/assets", &params)
+            .await
     }
 
     /// 查询指定币种资产（v2，推荐）
-    pub fn get_asset_v2(&self, coin: &str) -> anyhow::Result<String> {
+    pub fn get_asset_v2(&self, coin: &str) -> Result<Vec<AssetInfoResp>> {
+        let mut params = BTreeMap::new();
+        params.insert("coin".to_string(), coin.to_string());
+        self.request_typed(crate::consts::GET, "/api/v2/spot/account/assets", &params)
+    }
+
+    /// 查询指定币种资产（v2，推荐，异步版本）
+    pub async fn get_asset_v2_async(&self, coin: &str) -> Result<Vec<AssetInfoResp>> {
         let mut params = BTreeMap::new();
         params.insert("coin".to_string(), coin.to_string());
-        self.request(
+        self.request_typed_async(crate::consts::GET, "/api/v2/spot/account/assets", &params)
+            .await
+    }
+
+    /// 查询充值记录（v2，单页）
+    pub fn get_deposit_records(
+        &self,
+        query: &DepositRecordsQuery,
+    ) -> Result<Vec<WalletRecordResp>> {
+        self.request_typed(
+            crate::consts::GET,
+            "/api/v2/spot/wallet/deposit-records",
+            query,
+        )
+    }
+
+    /// 查询充值记录（v2，单页，异步版本）
+    pub async fn get_deposit_records_async(
+        &self,
+        query: &DepositRecordsQuery,
+    ) -> Result<Vec<WalletRecordResp>> {
+        self.request_typed_async(
+            crate::consts::GET,
+            "/api/v2/spot/wallet/deposit-records",
+            query,
+        )
+        .await
+    }
+
+    /// 按游标分页查询充值记录，从最新记录向前翻页
+    pub fn deposit_records_pages(
+        &self,
+        query: &DepositRecordsQuery,
+    ) -> Result<Paginator<'_, WalletRecordResp>> {
+        Paginator::new(
+            self,
+            "/api/v2/spot/wallet/deposit-records",
+            query,
+            WALLET_RECORDS_PAGE_SIZE,
+        )
+    }
+
+    /// 查询提现记录（v2，单页）
+    pub fn get_withdrawal_records(
+        &self,
+        query: &WithdrawalRecordsQuery,
+    ) -> Result<Vec<WalletRecordResp>> {
+        self.request_typed(
+            crate::consts::GET,
+            "/api/v2/spot/wallet/withdrawal-records",
+            query,
+        )
+    }
+
+    /// 查询提现记录（v2，单页，异步版本）
+    pub async fn get_withdrawal_records_async(
+        &self,
+        query: &WithdrawalRecordsQuery,
+    ) -> Result<Vec<WalletRecordResp>> {
+        self.request_typed_async(
             crate::consts::GET,
-            "/api/v2/spot/account/assets",
-            &params,
-            false,
+            "/api/v2/spot/wallet/withdrawal-records",
+            query,
+        )
+        .await
+    }
+
+    /// 按游标分页查询提现记录，从最新记录向前翻页
+    pub fn withdrawal_records_pages(
+        &self,
+        query: &WithdrawalRecordsQuery,
+    ) -> Result<Paginator<'_, WalletRecordResp>> {
+        Paginator::new(
+            self,
+            "/api/v2/spot/wallet/withdrawal-records",
+            query,
+            WALLET_RECORDS_PAGE_SIZE,
+        )
+    }
+
+    /// 查询任意时间区间内的充值记录，按最大跨度拆分窗口并分页，去重后按时间升序返回
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub fn get_deposit_records_range(
+        &self,
+        query: &DepositRecordsQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<WalletRecordResp>> {
+        self.get_time_range(
+            "/api/v2/spot/wallet/deposit-records",
+            query,
+            WALLET_RECORDS_PAGE_SIZE,
+            WALLET_RECORDS_MAX_SPAN,
+            from,
+            to,
+        )
+    }
+
+    /// 查询任意时间区间内的充值记录（异步版本）
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub async fn get_deposit_records_range_async(
+        &self,
+        query: &DepositRecordsQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<WalletRecordResp>> {
+        self.get_time_range_async(
+            "/api/v2/spot/wallet/deposit-records",
+            query,
+            WALLET_RECORDS_PAGE_SIZE,
+            WALLET_RECORDS_MAX_SPAN,
+            from,
+            to,
+        )
+        .await
+    }
+
+    /// 查询任意时间区间内的提现记录，按最大跨度拆分窗口并分页，去重后按时间升序返回
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub fn get_withdrawal_records_range(
+        &self,
+        query: &WithdrawalRecordsQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<WalletRecordResp>> {
+        self.get_time_range(
+            "/api/v2/spot/wallet/withdrawal-records",
+            query,
+            WALLET_RECORDS_PAGE_SIZE,
+            WALLET_RECORDS_MAX_SPAN,
+            from,
+            to,
+        )
+    }
+
+    /// 查询任意时间区间内的提现记录（异步版本）
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub async fn get_withdrawal_records_range_async(
+        &self,
+        query: &WithdrawalRecordsQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<WalletRecordResp>> {
+        self.get_time_range_async(
+            "/api/v2/spot/wallet/withdrawal-records",
+            query,
+            WALLET_RECORDS_PAGE_SIZE,
+            WALLET_RECORDS_MAX_SPAN,
+            from,
+            to,
         )
+        .await
     }
 }
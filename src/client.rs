@@ -2,14 +2,27 @@
 //!
 //! 该模块提供了与 Bitget 交易所 API 交互的核心客户端实现
 //! 负责处理 API 请求、签名验证和错误处理
+//!
+//! 同一个客户端同时支持同步（`request`）与异步（`request_async`）两种发送方式，
+//! 二者共用 URL、签名与请求体的构造逻辑
 
-use anyhow::{Result, anyhow};
-use reqwest::blocking::Client;
 use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
-use std::collections::BTreeMap;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
 use std::str::FromStr;
+use std::sync::{Arc, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::warn;
 
-use crate::consts;
+use crate::consts::{self, Environment};
+use crate::credentials::{Credentials, SecretString};
+use crate::exceptions::{self, BitgetError, BitgetErrorKind, Result};
+use crate::middleware::{Middleware, RequestContext};
+use crate::rate_limit::{RateLimitMode, RateLimiter};
+use crate::response::{ApiResponse, MetaResponse, RawResponse};
+use crate::retry::RetryPolicy;
+use crate::signer::{HmacSigner, SignatureMethod, Signer};
+use crate::time_sync::{self, ClockSkew, SyncGuard};
 use crate::utils;
 
 /// Bitget 交易所客户端
@@ -18,23 +31,396 @@ use crate::utils;
 #[derive(Debug, Clone)]
 pub struct BitgetClient {
     /// API 密钥
-    pub api_key: String,
-    /// API 密钥对应的秘钥
-    pub api_secret_key: String,
+    api_key: SecretString,
     /// API 密码短语
-    pub passphrase: String,
-    /// 是否使用服务器时间
+    passphrase: SecretString,
+    /// 账户 UID（设置后按 UID 限频，同一 UID 下的多个 API Key 共享配额）
+    uid: Option<String>,
+    /// 是否使用服务器时间（签名时间戳自动补偿本地时钟偏差）
     pub use_server_time: bool,
     /// 是否为首次请求（用于调试）
     pub first: bool,
-    /// HTTP 客户端
-    http_client: Client,
+    /// 交易环境（实盘 / 模拟盘）
+    environment: Environment,
+    /// 请求签名器（客户端不直接持有秘钥；公共模式下为 None，请求不签名）
+    signer: Option<Arc<dyn Signer>>,
+    /// 同步 HTTP 客户端（首次同步请求时才创建，避免在异步运行时内构造）
+    http_client: Arc<OnceLock<reqwest::blocking::Client>>,
+    /// HTTP 连接配置（用于延迟创建同步客户端）
+    http_config: Arc<HttpConfig>,
+    /// 异步 HTTP 客户端
+    async_http_client: reqwest::Client,
     /// 基础 URL
     base_url: String,
+    /// 本地与服务器的时钟偏差（克隆的客户端之间共享）
+    clock_skew: Arc<ClockSkew>,
+    /// 接口限频器，None 表示不限频
+    rate_limiter: Option<Arc<RateLimiter>>,
+    /// 请求重试策略
+    retry_policy: RetryPolicy,
+    /// 请求中间件（按注册顺序调用）
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+/// HTTP 连接配置，同步与异步客户端共用
+#[derive(Debug, Clone)]
+struct HttpConfig {
+    /// 连接超时
+    connect_timeout: Duration,
+    /// 请求超时
+    timeout: Duration,
+    /// HTTP / SOCKS 代理
+    proxy: Option<reqwest::Proxy>,
+    /// User-Agent
+    user_agent: String,
+    /// 默认请求头（如 locale）
+    default_headers: HeaderMap,
+    /// 连接池空闲连接超时
+    pool_idle_timeout: Option<Duration>,
+    /// 每个主机最多保留的空闲连接数
+    pool_max_idle_per_host: Option<usize>,
+}
+
+impl HttpConfig {
+    /// 构造异步 HTTP 客户端
+    fn async_client(&self) -> Result<reqwest::Client> {
+        let mut builder = reqwest::Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.timeout)
+            .user_agent(self.user_agent.clone())
+            .default_headers(self.default_headers.clone())
+            .pool_idle_timeout(self.pool_idle_timeout);
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        Ok(builder.build()?)
+    }
+
+    /// 构造同步 HTTP 客户端
+    fn blocking_client(&self) -> Result<reqwest::blocking::Client> {
+        let mut builder = reqwest::blocking::Client::builder()
+            .connect_timeout(self.connect_timeout)
+            .timeout(self.timeout)
+            .user_agent(self.user_agent.clone())
+            .default_headers(self.default_headers.clone())
+            .pool_idle_timeout(self.pool_idle_timeout);
+        if let Some(max) = self.pool_max_idle_per_host {
+            builder = builder.pool_max_idle_per_host(max);
+        }
+        if let Some(proxy) = &self.proxy {
+            builder = builder.proxy(proxy.clone());
+        }
+        Ok(builder.build()?)
+    }
+}
+
+/// Bitget 客户端构造器
+///
+/// 支持配置基础 URL、超时、代理、User-Agent、locale 请求头与连接池参数
+#[derive(Debug, Clone)]
+pub struct ClientBuilder {
+    api_key: SecretString,
+    passphrase: SecretString,
+    uid: Option<String>,
+    signer: Option<Arc<dyn Signer>>,
+    public_only: bool,
+    use_server_time: bool,
+    first: bool,
+    environment: Environment,
+    time_sync_interval: Option<Duration>,
+    base_url: String,
+    connect_timeout: Duration,
+    timeout: Duration,
+    proxy: Option<String>,
+    user_agent: String,
+    locale: Option<String>,
+    pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    rate_limiter: Option<Arc<RateLimiter>>,
+    retry_policy: RetryPolicy,
+    middlewares: Vec<Arc<dyn Middleware>>,
+}
+
+impl Default for ClientBuilder {
+    fn default() -> Self {
+        Self {
+            api_key: SecretString::default(),
+            passphrase: SecretString::default(),
+            uid: None,
+            signer: None,
+            public_only: false,
+            use_server_time: false,
+            first: false,
+            environment: Environment::Live,
+            time_sync_interval: None,
+            base_url: consts::API_URL.to_string(),
+            connect_timeout: consts::DEFAULT_CONNECT_TIMEOUT,
+            timeout: consts::DEFAULT_TIMEOUT,
+            proxy: None,
+            user_agent: consts::USER_AGENT.to_string(),
+            locale: None,
+            pool_idle_timeout: Some(consts::DEFAULT_POOL_IDLE_TIMEOUT),
+            pool_max_idle_per_host: None,
+            rate_limiter: Some(Arc::new(RateLimiter::default())),
+            retry_policy: RetryPolicy::default(),
+            middlewares: Vec::new(),
+        }
+    }
+}
+
+impl ClientBuilder {
+    /// 创建默认配置的构造器
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置 API 密钥
+    pub fn api_key(mut self, api_key: impl Into<SecretString>) -> Self {
+        self.api_key = api_key.into();
+        self
+    }
+
+    /// 设置 API 密钥对应的秘钥（使用 HMAC-SHA256 签名）
+    pub fn api_secret_key(mut self, api_secret_key: impl Into<SecretString>) -> Self {
+        self.signer = Some(Arc::new(HmacSigner::new(api_secret_key)));
+        self
+    }
+
+    /// 设置 API 密码短语
+    pub fn passphrase(mut self, passphrase: impl Into<SecretString>) -> Self {
+        self.passphrase = passphrase.into();
+        self
+    }
+
+    /// 设置账户 UID（设置后按 UID 而不是 API Key 限频）
+    pub fn uid(mut self, uid: impl Into<String>) -> Self {
+        self.uid = Some(uid.into());
+        self
+    }
+
+    /// 设置 API 凭证（API 密钥、密码短语，并使用秘钥进行 HMAC-SHA256 签名）
+    pub fn credentials(mut self, credentials: &Credentials) -> Self {
+        self.api_key = SecretString::new(credentials.api_key());
+        self.passphrase = SecretString::new(credentials.passphrase());
+        self.signer = Some(Arc::new(credentials.signer()));
+        self
+    }
+
+    /// 设置自定义签名器（如 RSA、HSM、远程签名服务）
+    pub fn signer(mut self, signer: Arc<dyn Signer>) -> Self {
+        self.signer = Some(signer);
+        self
+    }
+
+    /// 仅访问公共接口（行情、服务器时间等）：不需要凭证，请求不签名、不携带 ACCESS-* 请求头
+    ///
+    /// 未调用该方法时必须设置秘钥或签名器，否则 `build` 返回错误
+    pub fn public_only(mut self) -> Self {
+        self.public_only = true;
+        self
+    }
+
+    /// 设置内置签名方式（HMAC-SHA256 或 RSA）
+    pub fn signature_method(mut self, signature_method: SignatureMethod) -> Self {
+        self.signer = Some(signature_method.into_signer());
+        self
+    }
+
+    /// 是否使用服务器时间
+    pub fn use_server_time(mut self, use_server_time: bool) -> Self {
+        self.use_server_time = use_server_time;
+        self
+    }
+
+    /// 时钟偏差刷新间隔
+    pub fn time_sync_interval(mut self, interval: Duration) -> Self {
+        self.time_sync_interval = Some(interval);
+        self
+    }
+
+    /// 是否为首次请求（用于调试）
+    pub fn first(mut self, first: bool) -> Self {
+        self.first = first;
+        self
+    }
+
+    /// 设置交易环境，模拟盘会自动携带 `paptrading` 请求头
+    pub fn environment(mut self, environment: Environment) -> Self {
+        self.environment = environment;
+        self
+    }
+
+    /// 设置基础 URL（地区接入点或本地 mock 服务）
+    pub fn base_url(mut self, base_url: impl Into<String>) -> Self {
+        self.base_url = base_url.into().trim_end_matches('/').to_string();
+        self
+    }
+
+    /// 设置连接超时
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = timeout;
+        self
+    }
+
+    /// 设置请求超时（含读取响应）
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 设置代理，支持 `http://`、`https://`、`socks5://`、`socks5h://`
+    pub fn proxy(mut self, proxy_url: impl Into<String>) -> Self {
+        self.proxy = Some(proxy_url.into());
+        self
+    }
+
+    /// 设置 User-Agent
+    pub fn user_agent(mut self, user_agent: impl Into<String>) -> Self {
+        self.user_agent = user_agent.into();
+        self
+    }
+
+    /// 设置 locale 请求头（如 "en-US"、"zh-CN"），影响错误消息语言
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = Some(locale.into());
+        self
+    }
+
+    /// 设置连接池空闲连接超时，None 表示不回收
+    pub fn pool_idle_timeout(mut self, timeout: Option<Duration>) -> Self {
+        self.pool_idle_timeout = timeout;
+        self
+    }
+
+    /// 设置每个主机最多保留的空闲连接数
+    pub fn pool_max_idle_per_host(mut self, max: usize) -> Self {
+        self.pool_max_idle_per_host = Some(max);
+        self
+    }
+
+    /// 使用内置频率限制表，并指定令牌不足时的处理方式（默认等待）
+    pub fn rate_limit_mode(mut self, mode: RateLimitMode) -> Self {
+        self.rate_limiter = Some(Arc::new(RateLimiter::new(mode)));
+        self
+    }
+
+    /// 使用指定的限频器（可在多个客户端之间共享）
+    pub fn rate_limiter(mut self, rate_limiter: Arc<RateLimiter>) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// 关闭客户端限频
+    pub fn disable_rate_limit(mut self) -> Self {
+        self.rate_limiter = None;
+        self
+    }
+
+    /// 设置请求重试策略（默认最多尝试 3 次，非幂等下单仅在携带 clientOid 时重试）
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// 注册请求中间件，可多次调用，按注册顺序执行
+    pub fn middleware(mut self, middleware: Arc<dyn Middleware>) -> Self {
+        self.middlewares.push(middleware);
+        self
+    }
+
+    /// 构造客户端
+    ///
+    /// # 返回
+    /// 返回 BitgetClient 实例，代理地址或请求头无效时返回错误
+    pub fn build(self) -> Result<BitgetClient> {
+        let proxy = match &self.proxy {
+            Some(url) => Some(reqwest::Proxy::all(url).map_err(|e| {
+                BitgetError::InvalidRequest(format!("无效的代理地址 {}: {}", url, e))
+            })?),
+            None => None,
+        };
+
+        let mut default_headers = HeaderMap::new();
+        if let Some(locale) = &self.locale {
+            default_headers.insert(
+                consts::LOCALE_HEADER,
+                HeaderValue::from_str(locale)
+                    .map_err(|e| BitgetError::InvalidRequest(format!("无效的 locale: {}", e)))?,
+            );
+        }
+
+        let http_config = HttpConfig {
+            connect_timeout: self.connect_timeout,
+            timeout: self.timeout,
+            proxy,
+            user_agent: self.user_agent,
+            default_headers,
+            pool_idle_timeout: self.pool_idle_timeout,
+            pool_max_idle_per_host: self.pool_max_idle_per_host,
+        };
+
+        let signer = match (self.signer, self.public_only) {
+            (Some(_), true) => {
+                return Err(BitgetError::InvalidRequest(
+                    "公共模式客户端不能设置秘钥或签名器".to_string(),
+                ));
+            }
+            (None, true) => None,
+            (None, false) => {
+                return Err(BitgetError::InvalidRequest(
+                    "未设置 API 秘钥或签名器；仅访问公共接口时请调用 public_only()".to_string(),
+                ));
+            }
+            (Some(signer), false) => {
+                if self.api_key.is_empty() || self.passphrase.is_empty() {
+                    return Err(BitgetError::InvalidRequest(
+                        "签名请求需要同时设置 API 密钥与密码短语".to_string(),
+                    ));
+                }
+                Some(signer)
+            }
+        };
+
+        let clock_skew = ClockSkew::default();
+        if let Some(interval) = self.time_sync_interval {
+            clock_skew.set_refresh_interval(interval);
+        }
+
+        Ok(BitgetClient {
+            api_key: self.api_key,
+            passphrase: self.passphrase,
+            uid: self.uid,
+            use_server_time: self.use_server_time,
+            first: self.first,
+            environment: self.environment,
+            signer,
+            http_client: Arc::new(OnceLock::new()),
+            async_http_client: http_config.async_client()?,
+            http_config: Arc::new(http_config),
+            base_url: self.base_url,
+            clock_skew: Arc::new(clock_skew),
+            rate_limiter: self.rate_limiter,
+            retry_policy: self.retry_policy,
+            middlewares: self.middlewares,
+        })
+    }
+}
+
+/// 已完成签名、可直接发送的请求
+struct PreparedRequest {
+    /// 完整 URL
+    url: String,
+    /// 请求头（含签名）
+    headers: HeaderMap,
+    /// 请求体（仅 POST）
+    body: Option<String>,
 }
 
 impl BitgetClient {
-    /// 创建新的 Bitget 客户端实例
+    /// 创建新的 Bitget 客户端实例（默认配置，更多选项见 `BitgetClient::builder`）
     ///
     /// # 参数
     /// * `api_key` - API 密钥
@@ -44,123 +430,856 @@ impl BitgetClient {
     /// * `first` - 是否为首次请求（用于调试）
     ///
     /// # 返回
-    /// 返回 BitgetClient 实例
+    /// 返回 BitgetClient 实例，凭证缺失或 HTTP 客户端创建失败时返回错误
     pub fn new(
         api_key: String,
         api_secret_key: String,
         passphrase: String,
         use_server_time: bool,
         first: bool,
-    ) -> Self {
-        Self {
+    ) -> Result<Self> {
+        Self::with_signer(
             api_key,
-            api_secret_key,
+            Arc::new(HmacSigner::new(api_secret_key)),
             passphrase,
             use_server_time,
             first,
-            http_client: Client::new(),
-            base_url: consts::API_URL.to_string(),
+        )
+    }
+
+    /// 使用自定义签名器创建 Bitget 客户端实例
+    ///
+    /// # 参数
+    /// * `api_key` - API 密钥
+    /// * `signer` - 请求签名器（如 HSM、远程签名服务）
+    /// * `passphrase` - API 密码短语
+    /// * `use_server_time` - 是否使用服务器时间
+    /// * `first` - 是否为首次请求（用于调试）
+    ///
+    /// # 返回
+    /// 返回 BitgetClient 实例，凭证缺失或 HTTP 客户端创建失败时返回错误
+    pub fn with_signer(
+        api_key: String,
+        signer: Arc<dyn Signer>,
+        passphrase: String,
+        use_server_time: bool,
+        first: bool,
+    ) -> Result<Self> {
+        ClientBuilder::new()
+            .api_key(api_key)
+            .signer(signer)
+            .passphrase(passphrase)
+            .use_server_time(use_server_time)
+            .first(first)
+            .build()
+    }
+
+    /// 创建客户端构造器
+    pub fn builder() -> ClientBuilder {
+        ClientBuilder::new()
+    }
+
+    /// 创建使用 RSA 签名的 Bitget 客户端实例
+    ///
+    /// # 参数
+    /// * `api_key` - API 密钥
+    /// * `private_key_pem` - RSA 私钥（PEM 格式，PKCS#8 或 PKCS#1）
+    /// * `passphrase` - API 密码短语
+    /// * `use_server_time` - 是否使用服务器时间
+    /// * `first` - 是否为首次请求（用于调试）
+    ///
+    /// # 返回
+    /// 返回 BitgetClient 实例，私钥无法解析时返回错误
+    pub fn new_rsa(
+        api_key: String,
+        private_key_pem: String,
+        passphrase: String,
+        use_server_time: bool,
+        first: bool,
+    ) -> Result<Self> {
+        let signature_method = SignatureMethod::rsa_from_pem(&private_key_pem)?;
+        Self::with_signer(
+            api_key,
+            signature_method.into_signer(),
+            passphrase,
+            use_server_time,
+            first,
+        )
+    }
+
+    /// 指定签名方式
+    ///
+    /// # 参数
+    /// * `signature_method` - 签名方式
+    ///
+    /// # 返回
+    /// 返回使用新签名方式的客户端
+    pub fn with_signature_method(mut self, signature_method: SignatureMethod) -> Self {
+        self.signer = Some(signature_method.into_signer());
+        self
+    }
+
+    /// 当前签名方式的类型名称（如 `HmacSHA256`、`RSA`，自定义签名器由其 `sign_type` 决定），
+    /// 公共模式客户端返回 None
+    pub fn signature_method(&self) -> Option<&str> {
+        self.signer.as_deref().map(Signer::sign_type)
+    }
+
+    /// 使用 API 凭证创建 Bitget 客户端实例
+    ///
+    /// # 参数
+    /// * `credentials` - API 凭证
+    /// * `use_server_time` - 是否使用服务器时间
+    ///
+    /// # 返回
+    /// 返回 BitgetClient 实例，凭证缺失或 HTTP 客户端创建失败时返回错误
+    pub fn from_credentials(credentials: &Credentials, use_server_time: bool) -> Result<Self> {
+        ClientBuilder::new()
+            .credentials(credentials)
+            .use_server_time(use_server_time)
+            .build()
+    }
+
+    /// 复用当前客户端的 HTTP 连接池、限频器、时钟偏差与其他配置，创建使用另一组凭证的客户端
+    ///
+    /// # 参数
+    /// * `credentials` - API 凭证
+    /// * `uid` - 账户 UID，None 时按 API Key 限频
+    ///
+    /// # 返回
+    /// 返回新的 BitgetClient 实例
+    pub fn with_credentials(&self, credentials: &Credentials, uid: Option<String>) -> Self {
+        self.with_account(
+            credentials.api_key(),
+            Arc::new(credentials.signer()),
+            credentials.passphrase(),
+            uid,
+        )
+    }
+
+    /// 复用当前客户端的 HTTP 连接池、限频器、时钟偏差与其他配置，创建使用另一账户的客户端
+    ///
+    /// # 参数
+    /// * `api_key` - API 密钥
+    /// * `signer` - 请求签名器
+    /// * `passphrase` - API 密码短语
+    /// * `uid` - 账户 UID，None 时按 API Key 限频
+    ///
+    /// # 返回
+    /// 返回新的 BitgetClient 实例
+    pub fn with_account(
+        &self,
+        api_key: impl Into<SecretString>,
+        signer: Arc<dyn Signer>,
+        passphrase: impl Into<SecretString>,
+        uid: Option<String>,
+    ) -> Self {
+        Self {
+            api_key: api_key.into(),
+            passphrase: passphrase.into(),
+            uid,
+            signer: Some(signer),
+            ..self.clone()
         }
     }
 
+    /// API 密钥
+    pub fn api_key(&self) -> &str {
+        self.api_key.expose()
+    }
+
+    /// 账户 UID
+    pub fn uid(&self) -> Option<&str> {
+        self.uid.as_deref()
+    }
+
+    /// 当前请求签名器
+    ///
+    /// 公共模式客户端返回 None
+    pub fn signer(&self) -> Option<&dyn Signer> {
+        self.signer.as_deref()
+    }
+
+    /// 时钟偏差估算器
+    pub fn clock_skew(&self) -> &ClockSkew {
+        &self.clock_skew
+    }
+
     /// 发送同步请求（支持 GET/POST），自动签名、构造 header
     ///
+    /// 注意：该方法会阻塞当前线程，请勿在异步运行时中调用，异步场景请使用 `request_async`
+    ///
     /// # 参数
     /// * `method` - 请求方法（GET/POST）
     /// * `request_path` - 请求路径
-    /// * `params` - 请求参数（BTreeMap，自动按 key 排序）
-    /// * `cursor` - 是否为分页请求
+    /// * `params` - 请求参数（任意可序列化类型：GET 时作为查询参数，POST 时作为 JSON 请求体）
     ///
     /// # 返回
     /// 返回请求结果字符串或错误
-    pub fn request(
+    pub fn request<P: Serialize + ?Sized>(
         &self,
         method: &str,
         request_path: &str,
-        params: &BTreeMap<String, String>,
-        cursor: bool,
+        params: &P,
     ) -> Result<String> {
-        let _cursor = cursor;
+        Ok(self.execute(method, request_path, params)?.body)
+    }
+
+    /// 发送同步请求，按重试策略重试，返回原始响应
+    fn execute<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<RawResponse> {
+        let retryable = self.retry_policy.allows(method, request_path, params);
+        let mut attempt = 1;
+        loop {
+            if let Some(_sync) = self.begin_time_sync(request_path)
+                && let Err(e) = self.sync_server_time()
+            {
+                warn!("校准服务器时间失败: {}", e);
+            }
+            match self.send(method, request_path, params) {
+                Err(e) if retryable && self.should_retry(attempt, &e) => {
+                    let delay = self.retry_policy.delay(attempt, &e);
+                    warn!(
+                        "请求失败，{:?} 后第 {} 次重试 - {}: {}",
+                        delay, attempt, request_path, e
+                    );
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// 发送同步请求（不做时间校准）
+    pub(crate) fn send<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<RawResponse> {
+        self.throttle(request_path)?;
+        self.dispatch(method, request_path, params)
+    }
+
+    /// 按限频器取得发送许可（同步阻塞等待）
+    pub(crate) fn throttle(&self, request_path: &str) -> Result<()> {
+        match &self.rate_limiter {
+            Some(limiter) => limiter.acquire_blocking(self.rate_limit_account(), request_path),
+            None => Ok(()),
+        }
+    }
+
+    /// 签名并发送同步请求（不经过限频器）
+    pub(crate) fn dispatch<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<RawResponse> {
+        let mut prepared = self.prepare(method, request_path, params)?;
+        let context = self.before_send(method, request_path, params, &mut prepared.headers)?;
+
+        let client = self.blocking_client()?;
+        let builder = match method {
+            consts::GET => client.get(&prepared.url),
+            _ => client
+                .post(&prepared.url)
+                .body(prepared.body.unwrap_or_default()),
+        };
+        let started = Instant::now();
+        let result = builder
+            .headers(prepared.headers)
+            .send()
+            .map_err(BitgetError::from)
+            .and_then(read_blocking);
+        self.after_receive(context.as_ref(), &result, started.elapsed());
+
+        self.check_status(request_path, result?)
+    }
+
+    /// 发送异步请求（支持 GET/POST），自动签名、构造 header
+    ///
+    /// 基于 reqwest 异步客户端实现，不会阻塞 tokio 工作线程
+    ///
+    /// # 参数
+    /// * `method` - 请求方法（GET/POST）
+    /// * `request_path` - 请求路径
+    /// * `params` - 请求参数（任意可序列化类型：GET 时作为查询参数，POST 时作为 JSON 请求体）
+    ///
+    /// # 返回
+    /// 返回请求结果字符串或错误
+    pub async fn request_async<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<String> {
+        Ok(self.execute_async(method, request_path, params).await?.body)
+    }
+
+    /// 发送异步请求，按重试策略重试，返回原始响应
+    async fn execute_async<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<RawResponse> {
+        let retryable = self.retry_policy.allows(method, request_path, params);
+        let mut attempt = 1;
+        loop {
+            if let Some(_sync) = self.begin_time_sync(request_path)
+                && let Err(e) = self.sync_server_time_async().await
+            {
+                warn!("校准服务器时间失败: {}", e);
+            }
+            match self.send_async(method, request_path, params).await {
+                Err(e) if retryable && self.should_retry(attempt, &e) => {
+                    let delay = self.retry_policy.delay(attempt, &e);
+                    warn!(
+                        "请求失败，{:?} 后第 {} 次重试 - {}: {}",
+                        delay, attempt, request_path, e
+                    );
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// 发送异步请求（不做时间校准）
+    pub(crate) async fn send_async<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<RawResponse> {
+        self.throttle_async(request_path).await?;
+        self.dispatch_async(method, request_path, params).await
+    }
+
+    /// 按限频器取得发送许可（异步等待）
+    pub(crate) async fn throttle_async(&self, request_path: &str) -> Result<()> {
+        match &self.rate_limiter {
+            Some(limiter) => {
+                limiter
+                    .acquire(self.rate_limit_account(), request_path)
+                    .await
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// 签名并发送异步请求（不经过限频器）
+    pub(crate) async fn dispatch_async<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<RawResponse> {
+        let mut prepared = self.prepare(method, request_path, params)?;
+        let context = self.before_send(method, request_path, params, &mut prepared.headers)?;
+
+        let builder = match method {
+            consts::GET => self.async_http_client.get(&prepared.url),
+            _ => self
+                .async_http_client
+                .post(&prepared.url)
+                .body(prepared.body.unwrap_or_default()),
+        };
+        let started = Instant::now();
+        let result = match builder.headers(prepared.headers).send().await {
+            Ok(response) => read_async(response).await,
+            Err(e) => Err(e.into()),
+        };
+        self.after_receive(context.as_ref(), &result, started.elapsed());
+
+        self.check_status(request_path, result?)
+    }
+
+    /// 发送同步请求并将响应 `data` 解析为指定类型
+    ///
+    /// 响应码不为 "00000" 时返回 `BitgetError::Api`
+    ///
+    /// # 参数
+    /// * `method` - 请求方法（GET/POST）
+    /// * `request_path` - 请求路径
+    /// * `params` - 请求参数（任意可序列化类型：GET 时作为查询参数，POST 时作为 JSON 请求体）
+    ///
+    /// # 返回
+    /// 返回解析后的 `data` 或错误
+    pub fn request_typed<T: DeserializeOwned, P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<T> {
+        let text = self.request(method, request_path, params)?;
+        Ok(ApiResponse::<T>::from_text(&text)?.data)
+    }
+
+    /// 发送异步请求并将响应 `data` 解析为指定类型
+    ///
+    /// 响应码不为 "00000" 时返回 `BitgetError::Api`
+    ///
+    /// # 参数
+    /// * `method` - 请求方法（GET/POST）
+    /// * `request_path` - 请求路径
+    /// * `params` - 请求参数（任意可序列化类型：GET 时作为查询参数，POST 时作为 JSON 请求体）
+    ///
+    /// # 返回
+    /// 返回解析后的 `data` 或错误
+    pub async fn request_typed_async<T: DeserializeOwned, P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<T> {
+        let text = self.request_async(method, request_path, params).await?;
+        Ok(ApiResponse::<T>::from_text(&text)?.data)
+    }
+
+    /// 发送同步请求，返回解析后的 `data` 及响应元数据
+    ///
+    /// 响应码不为 "00000" 时返回 `BitgetError::Api`
+    ///
+    /// # 参数
+    /// * `method` - 请求方法（GET/POST）
+    /// * `request_path` - 请求路径
+    /// * `params` - 请求参数（任意可序列化类型：GET 时作为查询参数，POST 时作为 JSON 请求体）
+    ///
+    /// # 返回
+    /// 返回业务数据与响应元数据（状态码、请求 ID、剩余配额、服务器时间）或错误
+    pub fn request_with_meta<T: DeserializeOwned, P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<MetaResponse<T>> {
+        let response = self.execute(method, request_path, params)?;
+        with_meta(response)
+    }
+
+    /// 发送异步请求，返回解析后的 `data` 及响应元数据
+    ///
+    /// 响应码不为 "00000" 时返回 `BitgetError::Api`
+    ///
+    /// # 参数
+    /// * `method` - 请求方法（GET/POST）
+    /// * `request_path` - 请求路径
+    /// * `params` - 请求参数（任意可序列化类型：GET 时作为查询参数，POST 时作为 JSON 请求体）
+    ///
+    /// # 返回
+    /// 返回业务数据与响应元数据（状态码、请求 ID、剩余配额、服务器时间）或错误
+    pub async fn request_with_meta_async<T: DeserializeOwned, P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<MetaResponse<T>> {
+        let response = self.execute_async(method, request_path, params).await?;
+        with_meta(response)
+    }
+
+    /// 获取同步 HTTP 客户端，首次调用时按配置创建
+    fn blocking_client(&self) -> Result<&reqwest::blocking::Client> {
+        if let Some(client) = self.http_client.get() {
+            return Ok(client);
+        }
+        let client = self.http_config.blocking_client()?;
+        Ok(self.http_client.get_or_init(|| client))
+    }
+
+    /// 交易环境
+    pub fn environment(&self) -> Environment {
+        self.environment
+    }
+
+    /// 接口限频器
+    pub fn rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_deref()
+    }
+
+    /// 基础 URL
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// 请求重试策略
+    pub fn retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// 依次调用中间件的 `before_send`，未注册中间件时返回 None
+    ///
+    /// 中间件修改后的请求头写回 `headers`
+    fn before_send<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+        headers: &mut HeaderMap,
+    ) -> Result<Option<RequestContext>> {
+        if self.middlewares.is_empty() {
+            return Ok(None);
+        }
+
+        let mut context = RequestContext {
+            method: method.to_string(),
+            path: request_path.to_string(),
+            params: serde_json::to_value(params)
+                .map_err(|e| BitgetError::InvalidRequest(format!("请求参数序列化失败: {}", e)))?,
+            headers: std::mem::take(headers),
+        };
+        for middleware in &self.middlewares {
+            middleware.before_send(&mut context)?;
+        }
+        headers.clone_from(&context.headers);
+        Ok(Some(context))
+    }
+
+    /// 依次调用中间件的 `after_receive`
+    fn after_receive(
+        &self,
+        context: Option<&RequestContext>,
+        result: &Result<RawResponse>,
+        latency: Duration,
+    ) {
+        if let Some(context) = context {
+            for middleware in &self.middlewares {
+                middleware.after_receive(context, result.as_ref(), latency);
+            }
+        }
+    }
+
+    /// 限频账户标识：优先使用 UID，未设置时使用 API Key
+    fn rate_limit_account(&self) -> &str {
+        self.uid.as_deref().unwrap_or(self.api_key.expose())
+    }
+
+    /// 第 `attempt` 次请求失败后是否继续重试
+    fn should_retry(&self, attempt: u32, err: &BitgetError) -> bool {
+        attempt < self.retry_policy.max_attempts && self.retry_policy.should_retry(err)
+    }
+
+    /// 发送请求前是否由当前请求校准服务器时间
+    ///
+    /// 偏差过期且不在失败退避期内时，仅第一个请求取得校准许可，其余并发请求沿用当前偏差
+    fn begin_time_sync(&self, request_path: &str) -> Option<SyncGuard<'_>> {
+        if !self.use_server_time || request_path == time_sync::SERVER_TIME_PATH {
+            return None;
+        }
+        self.clock_skew.begin_sync()
+    }
+
+    /// 构造 URL、请求体并完成签名（同步与异步请求共用）
+    fn prepare<P: Serialize + ?Sized>(
+        &self,
+        method: &str,
+        request_path: &str,
+        params: &P,
+    ) -> Result<PreparedRequest> {
+        if method != consts::GET && method != consts::POST {
+            return Err(BitgetError::InvalidRequest(format!(
+                "不支持的 HTTP 方法: {}",
+                method
+            )));
+        }
+
         // 1. 构造 url
+        // 签名原文与实际请求使用同一编码后的路径，参数为空时不追加 `?`
         let mut full_path = request_path.to_string();
         if method == consts::GET {
-            full_path.push('?');
-            full_path.push_str(&utils::build_query(params));
+            full_path.push_str(&utils::parse_params_to_str(&utils::to_query_params(
+                params,
+            )?));
         }
 
         let url = format!("{}{}", self.base_url, full_path);
 
-        // 2. 构造 headers
-        let headers = self.build_headers(method, &full_path, params)?;
-
-        // 3. 构造 body (POST 请求)
+        // 2. 构造 body (POST 请求)，只序列化一次，签名与发送使用同一份字节
         let body = if method == consts::POST {
-            Some(serde_json::to_string(params)?)
+            Some(
+                serde_json::to_string(params)
+                    .map_err(|e| BitgetError::InvalidRequest(format!("序列化参数失败: {}", e)))?,
+            )
         } else {
             None
         };
 
-        // 4. 发送请求
-        let response = match method {
-            consts::GET => self.http_client.get(&url).headers(headers).send()?,
-            consts::POST => self
-                .http_client
-                .post(&url)
-                .headers(headers)
-                .body(body.unwrap())
-                .send()?,
-            _ => return Err(anyhow!("不支持的 HTTP 方法: {}", method)),
-        };
+        // 3. 构造 headers
+        let headers = self.build_headers(method, &full_path, body.as_deref().unwrap_or(""))?;
 
-        // 5. 处理响应
-        let status = response.status();
-        let text = response.text()?;
+        Ok(PreparedRequest { url, headers, body })
+    }
 
-        if !status.is_success() {
-            return Err(anyhow!("请求失败，状态码: {}, 响应: {}", status, text));
+    /// 检查 HTTP 状态码，非 2xx 时返回错误
+    ///
+    /// 429 状态码返回 `BitgetError::RateLimited`；
+    /// Bitget 在业务错误时通常同时返回 4xx 状态码与错误信封，此时返回 `BitgetError::Api`
+    fn check_status(&self, request_path: &str, response: RawResponse) -> Result<RawResponse> {
+        let status = response.status;
+        if status == reqwest::StatusCode::TOO_MANY_REQUESTS.as_u16() {
+            return Err(BitgetError::RateLimited {
+                path: request_path.to_string(),
+                retry_after: utils::retry_after(&response.headers),
+            });
+        }
+        if !(200..300).contains(&status) {
+            if let Some(err) = exceptions::parse_error_response(&response.body) {
+                if err.kind() == BitgetErrorKind::TimestampExpired {
+                    // 时间戳被拒绝，下一次请求前重新校准
+                    self.clock_skew.invalidate();
+                }
+                return Err(err.into());
+            }
+            return Err(BitgetError::Http {
+                status,
+                body: response.body,
+            });
         }
 
-        Ok(text)
+        Ok(response)
     }
 
-    fn build_headers(
-        &self,
-        method: &str,
-        full_path: &str,
-        params: &BTreeMap<String, String>,
-    ) -> Result<HeaderMap> {
+    fn build_headers(&self, method: &str, full_path: &str, body: &str) -> Result<HeaderMap> {
         let timestamp = if self.use_server_time {
-            // TODO: 实现获取服务器时间接口
-            utils::get_timestamp()
+            self.clock_skew.now_ms().to_string()
         } else {
             utils::get_timestamp()
         };
 
-        let body = if method == consts::POST {
-            serde_json::to_string(params).map_err(|e| anyhow!("序列化参数失败: {}", e))?
-        } else {
-            String::new()
-        };
-
-        let pre_hash = utils::pre_hash(&timestamp, method, full_path, &body);
-        let sign = match consts::SIGN_TYPE {
-            "RSA" => {
-                // TODO: 实现 RSA 签名
-                return Err(anyhow!("RSA 签名暂未实现"));
+        let mut headers = match &self.signer {
+            Some(signer) => {
+                let pre_hash = utils::pre_hash(&timestamp, method, full_path, body);
+                let sign = signer.sign(&pre_hash)?;
+                utils::get_header(
+                    self.api_key.expose(),
+                    &sign,
+                    &timestamp,
+                    self.passphrase.expose(),
+                )
             }
-            _ => utils::sign(&pre_hash, &self.api_secret_key)?,
+            // 公共模式：不签名，不携带凭证
+            None => vec![("Content-Type".to_string(), "application/json".to_string())],
         };
-
-        let headers = utils::get_header(&self.api_key, &sign, &timestamp, &self.passphrase);
+        if self.environment.is_demo() {
+            headers.push((consts::PAPTRADING_HEADER.to_string(), "1".to_string()));
+        }
 
         let mut header_map = HeaderMap::new();
         for (k, v) in headers {
-            header_map.insert(
-                HeaderName::from_str(&k).map_err(|e| anyhow!("无效的 header 名称: {}", e))?,
-                HeaderValue::from_str(&v).map_err(|e| anyhow!("无效的 header 值: {}", e))?,
-            );
+            let name = HeaderName::from_str(&k)
+                .map_err(|e| BitgetError::InvalidRequest(format!("无效的 header 名称: {}", e)))?;
+            let mut value = HeaderValue::from_str(&v)
+                .map_err(|e| BitgetError::InvalidRequest(format!("无效的 header 值: {}", e)))?;
+            // 凭证与签名请求头不出现在 HeaderMap 的 Debug 输出中
+            value.set_sensitive(utils::is_sensitive_header(&name));
+            header_map.insert(name, value);
         }
 
         Ok(header_map)
     }
 }
+
+/// 读取同步响应
+fn read_blocking(response: reqwest::blocking::Response) -> Result<RawResponse> {
+    let status = response.status().as_u16();
+    let headers = response.headers().clone();
+    let body = response.text()?;
+    Ok(RawResponse {
+        status,
+        headers,
+        body,
+    })
+}
+
+/// 读取异步响应
+async fn read_async(response: reqwest::Response) -> Result<RawResponse> {
+    let status = response.status().as_u16();
+    let headers = response.headers().clone();
+    let body = response.text().await?;
+    Ok(RawResponse {
+        status,
+        headers,
+        body,
+    })
+}
+
+/// 解析响应信封并附带响应元数据
+fn with_meta<T: DeserializeOwned>(response: RawResponse) -> Result<MetaResponse<T>> {
+    let mut meta = response.meta();
+    let envelope = ApiResponse::<T>::from_text(&response.body)?;
+    meta.request_time = envelope.request_time;
+    Ok(MetaResponse {
+        data: envelope.data,
+        meta,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+
+    /// 记录待签名字符串的签名器
+    #[derive(Debug, Default)]
+    struct RecordingSigner {
+        pre_hashes: Mutex<Vec<String>>,
+    }
+
+    impl Signer for RecordingSigner {
+        fn sign(&self, pre_hash: &str) -> Result<String> {
+            self.pre_hashes.lock().unwrap().push(pre_hash.to_string());
+            Ok("sign".to_string())
+        }
+    }
+
+    #[test]
+    fn fail_fast_rate_limit_is_not_retried() {
+        let path = "/api/v2/spot/market/tickers";
+        let limiter = Arc::new(
+            RateLimiter::new(RateLimitMode::FailFast)
+                .with_limit(crate::rate_limit::EndpointLimit::ip(path, 1)),
+        );
+        limiter.try_acquire("", path).unwrap();
+        let client = BitgetClient::builder()
+            .public_only()
+            .base_url("http://127.0.0.1:9")
+            .rate_limiter(limiter)
+            .retry_policy(RetryPolicy {
+                max_attempts: 5,
+                base_delay: Duration::from_secs(2),
+                ..RetryPolicy::default()
+            })
+            .build()
+            .unwrap();
+
+        let start = Instant::now();
+        let err = client
+            .request(consts::GET, path, &BTreeMap::<String, String>::new())
+            .unwrap_err();
+        assert!(
+            matches!(err, BitgetError::LocalRateLimited { .. }),
+            "{err:?}"
+        );
+        assert!(start.elapsed() < Duration::from_secs(1));
+    }
+
+    #[test]
+    fn url_and_pre_hash_share_the_encoded_path() {
+        let signer = Arc::new(RecordingSigner::default());
+        let client = BitgetClient::builder()
+            .api_key("key")
+            .passphrase("pass")
+            .signer(signer.clone())
+            .base_url("https://example.com")
+            .build()
+            .unwrap();
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), "BTC USDT".to_string());
+        params.insert("ids".to_string(), "1,2+3/4".to_string());
+        params.insert("coin".to_string(), "币".to_string());
+
+        let prepared = client
+            .prepare(consts::GET, "/api/v2/spot/trade/fills", &params)
+            .unwrap();
+        let path = prepared.url.strip_prefix("https://example.com").unwrap();
+        assert_eq!(
+            path,
+            "/api/v2/spot/trade/fills?coin=%E5%B8%81&ids=1%2C2%2B3%2F4&symbol=BTC%20USDT"
+        );
+
+        let pre_hash = signer.pre_hashes.lock().unwrap().pop().unwrap();
+        let signed_path = pre_hash.split_once("GET").unwrap().1;
+        assert_eq!(signed_path.as_bytes(), path.as_bytes());
+    }
+
+    #[test]
+    fn get_without_params_signs_bare_path() {
+        let signer = Arc::new(RecordingSigner::default());
+        let client = BitgetClient::builder()
+            .api_key("key")
+            .passphrase("pass")
+            .signer(signer.clone())
+            .base_url("https://example.com")
+            .build()
+            .unwrap();
+        let prepared = client
+            .prepare(
+                consts::GET,
+                "/api/v2/spot/account/assets",
+                &BTreeMap::<String, String>::new(),
+            )
+            .unwrap();
+        assert_eq!(
+            prepared.url,
+            "https://example.com/api/v2/spot/account/assets"
+        );
+        let pre_hash = signer.pre_hashes.lock().unwrap().pop().unwrap();
+        assert!(pre_hash.ends_with("GET/api/v2/spot/account/assets"));
+    }
+
+    #[test]
+    fn build_without_signer_is_rejected() {
+        let err = BitgetClient::builder()
+            .api_key("key")
+            .passphrase("pass")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, BitgetError::InvalidRequest(_)));
+    }
+
+    #[test]
+    fn build_with_signer_requires_key_and_passphrase() {
+        let err = BitgetClient::builder()
+            .api_secret_key("secret")
+            .build()
+            .unwrap_err();
+        assert!(matches!(err, BitgetError::InvalidRequest(_)));
+    }
+
+    #[test]
+    fn public_only_client_sends_no_credentials() {
+        let client = BitgetClient::builder().public_only().build().unwrap();
+        assert!(client.signer().is_none());
+        assert!(client.signature_method().is_none());
+
+        let headers = client
+            .build_headers(consts::GET, "/api/v2/public/time", "")
+            .unwrap();
+        assert!(headers.get("ACCESS-KEY").is_none());
+        assert!(headers.get("ACCESS-SIGN").is_none());
+    }
+
+    #[test]
+    fn public_only_rejects_credentials() {
+        assert!(
+            BitgetClient::builder()
+                .public_only()
+                .api_secret_key("secret")
+                .build()
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn constructors_return_signed_clients() {
+        let client =
+            BitgetClient::new("key".into(), "secret".into(), "pass".into(), false, false).unwrap();
+        assert_eq!(client.signature_method(), Some(consts::SIGN_TYPE));
+
+        let credentials = Credentials::new("key", "secret", "pass");
+        let client = BitgetClient::from_credentials(&credentials, false).unwrap();
+        let headers = client
+            .build_headers(consts::GET, "/api/v2/spot/account/assets", "")
+            .unwrap();
+        assert_eq!(headers.get("ACCESS-KEY").unwrap(), "key");
+    }
+}
@@ -2,20 +2,94 @@
 //!
 //! 该模块定义了 Bitget API 使用的各种常量
 
+use std::time::Duration;
+
 /// API 基础 URL
 pub const API_URL: &str = "https://api.bitget.com";
 
-/// WebSocket API URL
+/// WebSocket API URL（v1，已废弃）
 pub const WS_URL: &str = "wss://ws.bitget.com/spot/v1/stream";
 
+/// WebSocket 公共频道 URL（v2）
+pub const WS_PUBLIC_URL: &str = "wss://ws.bitget.com/v2/ws/public";
+
+/// WebSocket 私有频道 URL（v2）
+pub const WS_PRIVATE_URL: &str = "wss://ws.bitget.com/v2/ws/private";
+
+/// 模拟盘 WebSocket 公共频道 URL
+pub const DEMO_WS_PUBLIC_URL: &str = "wss://wspap.bitget.com/v2/ws/public";
+
+/// 模拟盘 WebSocket 私有频道 URL
+pub const DEMO_WS_PRIVATE_URL: &str = "wss://wspap.bitget.com/v2/ws/private";
+
+/// 模拟盘请求头
+pub const PAPTRADING_HEADER: &str = "paptrading";
+
 /// 请求方法: GET
 pub const GET: &str = "GET";
 
 /// 请求方法: POST
 pub const POST: &str = "POST";
 
-/// 签名类型
+/// 签名类型（默认 HMAC）
 pub const SIGN_TYPE: &str = "HmacSHA256";
 
+/// 签名类型: RSA
+pub const SIGN_TYPE_RSA: &str = "RSA";
+
+/// 默认连接超时
+pub const DEFAULT_CONNECT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// 默认请求超时
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 默认连接池空闲连接超时
+pub const DEFAULT_POOL_IDLE_TIMEOUT: Duration = Duration::from_secs(90);
+
+/// 默认 User-Agent
+pub const USER_AGENT: &str = concat!("bitget_sdk/", env!("CARGO_PKG_VERSION"));
+
+/// 语言请求头
+pub const LOCALE_HEADER: &str = "locale";
+
+/// 响应头: 当前限频窗口剩余请求数
+pub const USED_REMAIN_LIMIT_HEADER: &str = "x-mbx-used-remain-limit";
+
+/// 响应头: 请求 ID
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
 /// 内容类型
 pub const CONTENT_TYPE: &str = "application/json";
+
+/// 交易环境
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Environment {
+    /// 实盘
+    #[default]
+    Live,
+    /// 模拟盘（REST 请求携带 `paptrading: 1`，WebSocket 使用 wspap 接入点）
+    Demo,
+}
+
+impl Environment {
+    /// 是否为模拟盘
+    pub fn is_demo(&self) -> bool {
+        *self == Environment::Demo
+    }
+
+    /// WebSocket 公共频道 URL
+    pub fn ws_public_url(&self) -> &'static str {
+        match self {
+            Environment::Live => WS_PUBLIC_URL,
+            Environment::Demo => DEMO_WS_PUBLIC_URL,
+        }
+    }
+
+    /// WebSocket 私有频道 URL
+    pub fn ws_private_url(&self) -> &'static str {
+        match self {
+            Environment::Live => WS_PRIVATE_URL,
+            Environment::Demo => DEMO_WS_PRIVATE_URL,
+        }
+    }
+}
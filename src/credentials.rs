@@ -0,0 +1,315 @@
+//! API 凭证
+//!
+//! `Credentials` 持有 API Key、秘钥与密码短语，构造后只读，drop 时清零内存，
+//! `Debug` / `Display` 只输出脱敏后的 API Key，避免凭证随日志泄露
+//!
+//! 凭证可从环境变量、TOML 配置文件（多个命名 profile）或加密密钥库（见 `keystore` 模块）加载
+
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use zeroize::Zeroizing;
+
+use crate::exceptions::{BitgetError, Result};
+use crate::keystore::Keystore;
+use crate::signer::HmacSigner;
+
+/// 默认环境变量前缀
+pub const DEFAULT_ENV_PREFIX: &str = "BITGET";
+
+/// 脱敏占位符
+const REDACTED: &str = "***";
+
+/// 敏感字符串
+///
+/// drop 时清零内存，`Debug` / `Display` 不输出内容
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct SecretString(Zeroizing<String>);
+
+impl SecretString {
+    /// 创建敏感字符串
+    pub fn new(value: impl Into<String>) -> Self {
+        Self(Zeroizing::new(value.into()))
+    }
+
+    /// 读取明文
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl From<String> for SecretString {
+    fn from(value: String) -> Self {
+        Self::new(value)
+    }
+}
+
+impl From<&str> for SecretString {
+    fn from(value: &str) -> Self {
+        Self::new(value)
+    }
+}
+
+impl<'de> Deserialize<'de> for SecretString {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer).map(Self::new)
+    }
+}
+
+impl fmt::Debug for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+impl fmt::Display for SecretString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(REDACTED)
+    }
+}
+
+/// API 凭证
+#[derive(Clone, PartialEq, Eq, Deserialize)]
+pub struct Credentials {
+    /// API 密钥
+    api_key: SecretString,
+    /// API 密钥对应的秘钥
+    #[serde(alias = "secret_key")]
+    api_secret_key: SecretString,
+    /// API 密码短语
+    passphrase: SecretString,
+}
+
+impl Credentials {
+    /// 创建 API 凭证
+    ///
+    /// # 参数
+    /// * `api_key` - API 密钥
+    /// * `api_secret_key` - API 密钥对应的秘钥
+    /// * `passphrase` - API 密码短语
+    pub fn new(
+        api_key: impl Into<String>,
+        api_secret_key: impl Into<String>,
+        passphrase: impl Into<String>,
+    ) -> Self {
+        Self {
+            api_key: SecretString::new(api_key),
+            api_secret_key: SecretString::new(api_secret_key),
+            passphrase: SecretString::new(passphrase),
+        }
+    }
+
+    /// API 密钥
+    pub fn api_key(&self) -> &str {
+        self.api_key.expose()
+    }
+
+    /// API 密钥对应的秘钥
+    pub fn api_secret_key(&self) -> &str {
+        self.api_secret_key.expose()
+    }
+
+    /// API 密码短语
+    pub fn passphrase(&self) -> &str {
+        self.passphrase.expose()
+    }
+
+    /// 脱敏后的 API 密钥（仅保留前 4 位），可用于日志
+    pub fn masked_api_key(&self) -> String {
+        mask(self.api_key())
+    }
+
+    /// 使用秘钥创建 HMAC-SHA256 签名器
+    pub fn signer(&self) -> HmacSigner {
+        HmacSigner::new(self.api_secret_key.clone())
+    }
+
+    /// 从环境变量加载凭证（`BITGET_API_KEY` / `BITGET_API_SECRET_KEY` / `BITGET_PASSPHRASE`）
+    ///
+    /// # 返回
+    /// 返回凭证，环境变量缺失时返回错误
+    pub fn from_env() -> Result<Self> {
+        Self::from_env_with_prefix(DEFAULT_ENV_PREFIX)
+    }
+
+    /// 从指定前缀的环境变量加载凭证
+    ///
+    /// # 参数
+    /// * `prefix` - 环境变量前缀，如 "BITGET_SUB1" 读取 `BITGET_SUB1_API_KEY` 等
+    ///
+    /// # 返回
+    /// 返回凭证，环境变量缺失时返回错误
+    pub fn from_env_with_prefix(prefix: &str) -> Result<Self> {
+        let var = |name: &str| {
+            let key = format!("{}_{}", prefix, name);
+            std::env::var(&key)
+                .map_err(|e| BitgetError::Credentials(format!("环境变量 {} 读取失败: {}", key, e)))
+        };
+        Ok(Self::new(
+            var("API_KEY")?,
+            var("API_SECRET_KEY")?,
+            var("PASSPHRASE")?,
+        ))
+    }
+
+    /// 从 TOML 配置文件加载指定 profile 的凭证
+    ///
+    /// 文件中每个表（如 `[main]`）对应一个 profile，包含 `api_key`、`api_secret_key`、`passphrase` 三个字段
+    ///
+    /// # 参数
+    /// * `path` - 配置文件路径
+    /// * `profile` - profile 名称
+    ///
+    /// # 返回
+    /// 返回凭证，文件无法读取、格式错误或 profile 不存在时返回错误
+    pub fn from_toml_file(path: impl AsRef<Path>, profile: &str) -> Result<Self> {
+        take_profile(profiles_from_toml_file(path)?, profile)
+    }
+
+    /// 从 TOML 文本加载指定 profile 的凭证
+    ///
+    /// # 参数
+    /// * `content` - TOML 文本
+    /// * `profile` - profile 名称
+    ///
+    /// # 返回
+    /// 返回凭证，格式错误或 profile 不存在时返回错误
+    pub fn from_toml_str(content: &str, profile: &str) -> Result<Self> {
+        take_profile(profiles_from_toml_str(content)?, profile)
+    }
+
+    /// 从加密密钥库加载指定 profile 的凭证
+    ///
+    /// # 参数
+    /// * `path` - 密钥库文件路径
+    /// * `password` - 密钥库口令
+    /// * `profile` - profile 名称
+    ///
+    /// # 返回
+    /// 返回凭证，口令错误、文件损坏或 profile 不存在时返回错误
+    pub fn from_keystore(path: impl AsRef<Path>, password: &str, profile: &str) -> Result<Self> {
+        Keystore::open(path, password)?.profile(profile).cloned()
+    }
+}
+
+/// 读取 TOML 配置文件中的全部 profile
+///
+/// # 参数
+/// * `path` - 配置文件路径
+///
+/// # 返回
+/// 返回 profile 名称到凭证的映射或错误
+pub fn profiles_from_toml_file(path: impl AsRef<Path>) -> Result<BTreeMap<String, Credentials>> {
+    let path = path.as_ref();
+    let content = Zeroizing::new(std::fs::read_to_string(path).map_err(|e| {
+        BitgetError::Credentials(format!("读取配置文件 {} 失败: {}", path.display(), e))
+    })?);
+    profiles_from_toml_str(&content)
+}
+
+/// 解析 TOML 文本中的全部 profile
+///
+/// # 参数
+/// * `content` - TOML 文本
+///
+/// # 返回
+/// 返回 profile 名称到凭证的映射或错误
+pub fn profiles_from_toml_str(content: &str) -> Result<BTreeMap<String, Credentials>> {
+    // 只保留错误描述，toml 的完整错误信息会带出原文片段
+    toml::from_str(content)
+        .map_err(|e| BitgetError::Credentials(format!("配置文件格式错误: {}", e.message())))
+}
+
+/// 从 profile 映射中取出指定 profile
+fn take_profile(mut profiles: BTreeMap<String, Credentials>, profile: &str) -> Result<Credentials> {
+    profiles
+        .remove(profile)
+        .ok_or_else(|| BitgetError::Credentials(format!("未找到 profile: {}", profile)))
+}
+
+impl fmt::Debug for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Credentials")
+            .field("api_key", &self.masked_api_key())
+            .field("api_secret_key", &self.api_secret_key)
+            .field("passphrase", &self.passphrase)
+            .finish()
+    }
+}
+
+impl fmt::Display for Credentials {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Credentials({})", self.masked_api_key())
+    }
+}
+
+/// 仅保留前 4 个字符，其余以 *** 代替
+pub(crate) fn mask(value: &str) -> String {
+    let prefix: String = value.chars().take(4).collect();
+    format!("{}{}", prefix, REDACTED)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROFILES_TOML: &str = r#"
+[main]
+api_key = "main-key"
+api_secret_key = "main-secret"
+passphrase = "main-pass"
+
+[sub1]
+api_key = "sub1-key"
+secret_key = "sub1-secret"
+passphrase = "sub1-pass"
+"#;
+
+    #[test]
+    fn profiles_are_loaded_from_toml() {
+        let profiles = profiles_from_toml_str(PROFILES_TOML).unwrap();
+        assert_eq!(profiles.keys().collect::<Vec<_>>(), ["main", "sub1"]);
+        assert_eq!(
+            profiles["main"],
+            Credentials::new("main-key", "main-secret", "main-pass")
+        );
+        // secret_key 是 api_secret_key 的别名
+        assert_eq!(profiles["sub1"].api_secret_key(), "sub1-secret");
+    }
+
+    #[test]
+    fn single_profile_is_selected_by_name() {
+        let credentials = Credentials::from_toml_str(PROFILES_TOML, "sub1").unwrap();
+        assert_eq!(credentials.api_key(), "sub1-key");
+
+        match Credentials::from_toml_str(PROFILES_TOML, "sub2") {
+            Err(BitgetError::Credentials(msg)) => assert_eq!(msg, "未找到 profile: sub2"),
+            other => panic!("expected Credentials error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn toml_errors_do_not_leak_content() {
+        let content = "[main]\napi_key = \"main-key\"\napi_secret_key = \"top-secret\"\n";
+        match profiles_from_toml_str(content) {
+            Err(BitgetError::Credentials(msg)) => assert!(!msg.contains("top-secret"), "{msg}"),
+            other => panic!("expected Credentials error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn debug_output_is_redacted() {
+        let credentials = Credentials::new("abcdefgh", "top-secret", "pass-phrase");
+        let debug = format!("{credentials:?}");
+        assert!(debug.contains("abcd"));
+        assert!(!debug.contains("abcdefgh"));
+        assert!(!debug.contains("top-secret"));
+        assert!(!debug.contains("pass-phrase"));
+    }
+}
@@ -1,4 +0,0 @@
-//! 统一错误处理模块
-//! 使用 anyhow::Result 并支持错误上下文
-
-pub use anyhow::{Context, Result, anyhow};
@@ -1,9 +1,173 @@
 //! Bitget 交易所 API 异常处理
 //!
 //! 该模块定义了 Bitget API 交互过程中的特定异常类型
+//! 所有公开接口统一返回 `BitgetError`，便于调用方按错误类别决定重试、退避或放弃
 
 use serde::{Deserialize, Serialize};
 use std::fmt;
+use std::time::Duration;
+
+/// SDK 统一结果类型
+pub type Result<T, E = BitgetError> = std::result::Result<T, E>;
+
+/// Bitget SDK 统一错误类型
+#[derive(Debug)]
+pub enum BitgetError {
+    /// 网络传输错误（连接失败、超时等）
+    Transport(reqwest::Error),
+
+    /// HTTP 状态码非 2xx，且响应体不是 Bitget 错误结构
+    Http {
+        /// HTTP 状态码
+        status: u16,
+        /// 响应体
+        body: String,
+    },
+
+    /// Bitget 业务错误（响应码不为 "00000"）
+    Api(BitgetApiError),
+
+    /// 响应解析失败
+    Decode {
+        /// 解析错误
+        source: serde_json::Error,
+        /// 原始响应内容
+        payload: String,
+    },
+
+    /// 请求签名失败
+    Signing(String),
+
+    /// 触发限频（服务端返回 HTTP 429）
+    RateLimited {
+        /// 请求路径
+        path: String,
+        /// 建议的等待时间
+        retry_after: Option<Duration>,
+    },
+
+    /// 本地限频器令牌不足（`RateLimitMode::FailFast`），请求未发出
+    LocalRateLimited {
+        /// 请求路径
+        path: String,
+        /// 令牌恢复所需的等待时间
+        retry_after: Duration,
+    },
+
+    /// 请求参数或请求构造错误
+    InvalidRequest(String),
+
+    /// 凭证加载失败（环境变量缺失、配置文件错误、密钥库口令错误等）
+    Credentials(String),
+
+    /// WebSocket 错误
+    WebSocket(Box<tokio_tungstenite::tungstenite::Error>),
+}
+
+impl BitgetError {
+    /// 构造响应解析错误
+    ///
+    /// # 参数
+    /// * `source` - 解析错误
+    /// * `payload` - 原始响应内容
+    pub fn decode(source: serde_json::Error, payload: impl Into<String>) -> Self {
+        BitgetError::Decode {
+            source,
+            payload: payload.into(),
+        }
+    }
+}
+
+impl fmt::Display for BitgetError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BitgetError::Transport(e) => write!(f, "网络传输错误: {}", e),
+            BitgetError::Http { status, body } => {
+                write!(f, "请求失败，状态码: {}, 响应: {}", status, body)
+            }
+            BitgetError::Api(e) => write!(f, "{}", e),
+            BitgetError::Decode { source, payload } => {
+                write!(f, "响应解析失败: {}, 响应: {}", source, payload)
+            }
+            BitgetError::Signing(msg) => write!(f, "签名失败: {}", msg),
+            BitgetError::RateLimited { path, retry_after } => match retry_after {
+                Some(d) => write!(f, "触发限频: {}, 建议等待 {:?}", path, d),
+                None => write!(f, "触发限频: {}", path),
+            },
+            BitgetError::LocalRateLimited { path, retry_after } => {
+                write!(f, "本地限频令牌不足: {}, 需等待 {:?}", path, retry_after)
+            }
+            BitgetError::InvalidRequest(msg) => write!(f, "无效请求: {}", msg),
+            BitgetError::Credentials(msg) => write!(f, "凭证加载失败: {}", msg),
+            BitgetError::WebSocket(e) => write!(f, "WebSocket 错误: {}", e),
+        }
+    }
+}
+
+impl std::error::Error for BitgetError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BitgetError::Transport(e) => Some(e),
+            BitgetError::Api(e) => Some(e),
+            BitgetError::Decode { source, .. } => Some(source),
+            BitgetError::WebSocket(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl BitgetError {
+    /// 若为 Bitget 业务错误，返回其语义分类
+    pub fn api_kind(&self) -> Option<BitgetErrorKind> {
+        match self {
+            BitgetError::Api(e) => Some(e.kind()),
+            _ => None,
+        }
+    }
+
+    /// 是否为可重试错误（超时/连接失败、5xx、限频、可重试的业务错误码）
+    ///
+    /// 本地限频（`LocalRateLimited`）不算在内：调用方选择了 `FailFast`，应由调用方决定是否等待
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            BitgetError::Transport(e) => e.is_timeout() || e.is_connect(),
+            BitgetError::Http { status, .. } => *status >= 500,
+            BitgetError::RateLimited { .. } => true,
+            BitgetError::Api(e) => e.kind().is_retryable(),
+            _ => false,
+        }
+    }
+
+    /// 是否为致命错误（原样重试不可能成功）
+    pub fn is_fatal(&self) -> bool {
+        match self {
+            BitgetError::Http { status, .. } => (400..500).contains(status),
+            BitgetError::Api(e) => e.kind().is_fatal(),
+            BitgetError::Signing(_)
+            | BitgetError::InvalidRequest(_)
+            | BitgetError::Credentials(_) => true,
+            _ => false,
+        }
+    }
+}
+
+impl From<reqwest::Error> for BitgetError {
+    fn from(e: reqwest::Error) -> Self {
+        BitgetError::Transport(e)
+    }
+}
+
+impl From<BitgetApiError> for BitgetError {
+    fn from(e: BitgetApiError) -> Self {
+        BitgetError::Api(e)
+    }
+}
+
+impl From<tokio_tungstenite::tungstenite::Error> for BitgetError {
+    fn from(e: tokio_tungstenite::tungstenite::Error) -> Self {
+        BitgetError::WebSocket(Box::new(e))
+    }
+}
 
 /// Bitget API 响应错误结构
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -29,6 +193,109 @@ impl fmt::Display for BitgetApiError {
 
 impl std::error::Error for BitgetApiError {}
 
+impl BitgetApiError {
+    /// 返回错误码对应的语义分类
+    pub fn kind(&self) -> BitgetErrorKind {
+        BitgetErrorKind::from_code(&self.code)
+    }
+}
+
+/// Bitget 错误码语义分类
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BitgetErrorKind {
+    /// 余额不足
+    InsufficientBalance,
+    /// 签名错误
+    InvalidSignature,
+    /// API Key / 密码短语无效
+    InvalidApiKey,
+    /// 时间戳过期或无效
+    TimestampExpired,
+    /// 请求过于频繁
+    RateLimited,
+    /// 订单不存在
+    OrderNotFound,
+    /// clientOid 重复
+    DuplicateClientOid,
+    /// 交易对已下线
+    SymbolOffline,
+    /// 系统繁忙或维护中
+    SystemBusy,
+    /// 权限不足、IP 不在白名单或账户被冻结
+    PermissionDenied,
+    /// 参数校验失败
+    InvalidParameter,
+    /// 未收录的错误码
+    Unknown,
+}
+
+/// 常见 Bitget 错误码目录
+///
+/// 未收录的错误码归为 `BitgetErrorKind::Unknown`
+pub const ERROR_CODES: &[(&str, BitgetErrorKind)] = &[
+    ("40001", BitgetErrorKind::InvalidApiKey),
+    ("40002", BitgetErrorKind::InvalidSignature),
+    ("40003", BitgetErrorKind::InvalidSignature),
+    ("40005", BitgetErrorKind::TimestampExpired),
+    ("40006", BitgetErrorKind::InvalidApiKey),
+    ("40008", BitgetErrorKind::TimestampExpired),
+    ("40009", BitgetErrorKind::InvalidSignature),
+    ("40011", BitgetErrorKind::InvalidApiKey),
+    ("40012", BitgetErrorKind::InvalidApiKey),
+    ("40013", BitgetErrorKind::PermissionDenied),
+    ("40014", BitgetErrorKind::PermissionDenied),
+    ("40015", BitgetErrorKind::SystemBusy),
+    ("40017", BitgetErrorKind::InvalidParameter),
+    ("40018", BitgetErrorKind::PermissionDenied),
+    ("40019", BitgetErrorKind::InvalidParameter),
+    ("40034", BitgetErrorKind::InvalidParameter),
+    ("40037", BitgetErrorKind::InvalidApiKey),
+    ("40200", BitgetErrorKind::SystemBusy),
+    ("40309", BitgetErrorKind::SymbolOffline),
+    ("40725", BitgetErrorKind::SystemBusy),
+    ("40754", BitgetErrorKind::InsufficientBalance),
+    ("40762", BitgetErrorKind::InsufficientBalance),
+    ("40768", BitgetErrorKind::OrderNotFound),
+    ("40786", BitgetErrorKind::DuplicateClientOid),
+    ("40808", BitgetErrorKind::InvalidParameter),
+    ("429", BitgetErrorKind::RateLimited),
+    ("43001", BitgetErrorKind::OrderNotFound),
+    ("43012", BitgetErrorKind::InsufficientBalance),
+    ("43025", BitgetErrorKind::OrderNotFound),
+];
+
+impl BitgetErrorKind {
+    /// 根据错误码查询语义分类
+    ///
+    /// # 参数
+    /// * `code` - Bitget 错误码
+    ///
+    /// # 返回
+    /// 返回对应分类，未收录时返回 `Unknown`
+    pub fn from_code(code: &str) -> Self {
+        ERROR_CODES
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(_, kind)| *kind)
+            .unwrap_or(BitgetErrorKind::Unknown)
+    }
+
+    /// 是否可重试（限频、系统繁忙、时间戳过期）
+    pub fn is_retryable(&self) -> bool {
+        matches!(
+            self,
+            BitgetErrorKind::RateLimited
+                | BitgetErrorKind::SystemBusy
+                | BitgetErrorKind::TimestampExpired
+        )
+    }
+
+    /// 是否为致命错误（原样重试不可能成功）
+    pub fn is_fatal(&self) -> bool {
+        !self.is_retryable() && *self != BitgetErrorKind::Unknown
+    }
+}
+
 /// 解析 API 响应中的错误
 ///
 /// # 参数
@@ -42,7 +309,7 @@ pub fn parse_error_response(response_text: &str) -> Option<BitgetApiError> {
             // 检查是否包含错误代码和消息
             if let (Some(code), Some(msg)) = (value.get("code"), value.get("msg")) {
                 // 检查是否为错误响应
-                if code.as_str().map_or(false, |c| c != "00000") {
+                if code.as_str().is_some_and(|c| c != "00000") {
                     let request_id = value
                         .get("requestId")
                         .and_then(|id| id.as_str())
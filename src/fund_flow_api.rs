@@ -1,23 +1,136 @@
 //! Bitget 资金流水、特殊划转等接口
+//! 示例：GET /api/v2/spot/account/bills
 use crate::client::BitgetClient;
-use anyhow::Result;
+use crate::exceptions::Result;
+use crate::pagination::{self, CursorItem, Paginator};
+use crate::rate_limit::EndpointLimit;
+use crate::time_window::{self, TimeRangeQuery};
 use serde::{Deserialize, Serialize};
+use std::time::Duration;
 use tracing::info;
 
-#[derive(Debug, Serialize)]
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[EndpointLimit::uid("/api/v2/spot/account/bills", 10)];
+
+/// 账单流水每页最大条数
+pub const BILLS_PAGE_SIZE: u32 = 500;
+
+/// 账单流水单次查询的最大时间跨度
+pub const BILLS_MAX_SPAN: Duration = time_window::NINETY_DAYS;
+
+/// 现货账单流水查询参数（v2）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FundFlowReq {
-    // TODO: 按官方文档定义
+    /// 币种
+    pub coin: Option<String>,
+    /// 账单大类（deposit / withdraw / transaction / transfer 等）
+    pub group_type: Option<String>,
+    /// 业务类型
+    pub business_type: Option<String>,
+    /// 开始时间（毫秒时间戳）
+    pub start_time: Option<i64>,
+    /// 结束时间（毫秒时间戳）
+    pub end_time: Option<i64>,
+    /// 每页条数，最大 500
+    pub limit: Option<u32>,
+    /// 游标：返回 billId 小于该值的记录
+    pub id_less_than: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+/// 现货账单流水（v2）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FundFlowResp {
-    // TODO: 按官方文档定义
+    pub bill_id: Option<String>,
+    pub coin: Option<String>,
+    pub group_type: Option<String>,
+    pub business_type: Option<String>,
+    pub size: Option<String>,
+    pub balance: Option<String>,
+    pub fees: Option<String>,
+    pub c_time: Option<String>,
+}
+
+impl CursorItem for FundFlowResp {
+    fn cursor_id(&self) -> Option<&str> {
+        self.bill_id.as_deref()
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        pagination::parse_ms(&self.c_time)
+    }
+}
+
+impl TimeRangeQuery for FundFlowReq {
+    fn set_time_range(&mut self, start_ms: i64, end_ms: i64) {
+        self.start_time = Some(start_ms);
+        self.end_time = Some(end_ms);
+        self.id_less_than = None;
+    }
 }
 
 impl BitgetClient {
-    pub fn get_fund_flow(&self, req: FundFlowReq) -> Result<FundFlowResp> {
-        info!("查询资金流水: {:?}", req);
-        // TODO: 实现资金流水查询
-        Ok(FundFlowResp { /* ... */ })
+    /// 查询资金流水（现货账单，单页）
+    pub fn get_fund_flow(&self, req: &FundFlowReq) -> Result<Vec<FundFlowResp>> {
+        info!("查询资金流水 coin={:?}", req.coin);
+        self.request_typed(crate::consts::GET, "/api/v2/spot/account/bills", req)
+    }
+
+    /// 查询资金流水（现货账单，单页，异步版本）
+    pub async fn get_fund_flow_async(&self, req: &FundFlowReq) -> Result<Vec<FundFlowResp>> {
+        info!("查询资金流水 coin={:?}", req.coin);
+        self.request_typed_async(crate::consts::GET, "/api/v2/spot/account/bills", req)
+            .await
+    }
+
+    /// 按游标分页查询资金流水，从最新记录向前翻页
+    pub fn fund_flow_pages(&self, req: &FundFlowReq) -> Result<Paginator<'_, FundFlowResp>> {
+        Paginator::new(self, "/api/v2/spot/account/bills", req, BILLS_PAGE_SIZE)
+    }
+
+    /// 查询任意时间区间内的资金流水，按最大跨度拆分窗口并分页，去重后按时间升序返回
+    ///
+    /// # 参数
+    /// * `req` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub fn get_fund_flow_range(
+        &self,
+        req: &FundFlowReq,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<FundFlowResp>> {
+        self.get_time_range(
+            "/api/v2/spot/account/bills",
+            req,
+            BILLS_PAGE_SIZE,
+            BILLS_MAX_SPAN,
+            from,
+            to,
+        )
+    }
+
+    /// 查询任意时间区间内的资金流水（异步版本）
+    ///
+    /// # 参数
+    /// * `req` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub async fn get_fund_flow_range_async(
+        &self,
+        req: &FundFlowReq,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<FundFlowResp>> {
+        self.get_time_range_async(
+            "/api/v2/spot/account/bills",
+            req,
+            BILLS_PAGE_SIZE,
+            BILLS_MAX_SPAN,
+            from,
+            to,
+        )
+        .await
     }
 }
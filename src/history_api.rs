@@ -1,30 +1,303 @@
 //! Bitget 历史成交相关接口
-//! 示例：GET /api/mix/v1/order/fills
+//! 示例：GET /api/mix/v1/order/fills、GET /api/v2/spot/trade/fills
 use crate::client::BitgetClient;
-use anyhow::Result;
-use serde::Deserialize;
+use crate::exceptions::Result;
+use crate::order_api::{OrderType, Side};
+use crate::pagination::{self, CursorItem, Paginator};
+use crate::rate_limit::EndpointLimit;
+use crate::time_window::{self, TimeRangeQuery};
+use crate::utils;
+use serde::{Deserialize, Deserializer, Serialize};
 use std::collections::BTreeMap;
+use std::time::Duration;
 
-#[derive(Debug, Deserialize)]
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[
+    EndpointLimit::uid("/api/mix/v1/order/fills", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/fills", 10),
+];
+
+/// 现货成交明细每页最大条数
+pub const SPOT_FILLS_PAGE_SIZE: u32 = 100;
+
+/// 现货成交明细单次查询的最大时间跨度
+pub const SPOT_FILLS_MAX_SPAN: Duration = time_window::NINETY_DAYS;
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct FillResp {
     pub trade_id: Option<String>,
+    pub symbol: Option<String>,
     pub order_id: Option<String>,
+    /// 成交价格
     pub price: Option<String>,
+    /// 成交数量
+    #[serde(alias = "sizeQty")]
+    pub size: Option<String>,
+    /// 成交金额
+    pub fill_amount: Option<String>,
+    /// 手续费
+    pub fee: Option<String>,
+    /// 创建时间（毫秒时间戳）
+    pub c_time: Option<String>,
+}
+
+/// 现货成交明细查询参数（v2）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotFillsQuery {
+    /// 交易对
+    pub symbol: String,
+    /// 订单 ID
+    pub order_id: Option<String>,
+    /// 开始时间（毫秒时间戳）
+    pub start_time: Option<i64>,
+    /// 结束时间（毫秒时间戳）
+    pub end_time: Option<i64>,
+    /// 每页条数，最大 100
+    pub limit: Option<u32>,
+    /// 游标：返回 tradeId 小于该值的记录
+    pub id_less_than: Option<String>,
+}
+
+impl SpotFillsQuery {
+    /// 创建指定交易对的查询参数
+    pub fn new(symbol: impl Into<String>) -> Self {
+        Self {
+            symbol: symbol.into(),
+            ..Self::default()
+        }
+    }
+}
+
+/// 成交角色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TradeScope {
+    /// 吃单方
+    Taker,
+    /// 挂单方
+    Maker,
+    /// 未知角色
+    #[serde(other)]
+    Unknown,
+}
+
+/// 成交手续费明细
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FillFeeDetail {
+    /// 是否使用抵扣（接口返回 yes / no）
+    #[serde(default, deserialize_with = "yes_no")]
+    pub deduction: bool,
+    /// 手续费币种
+    pub fee_coin: Option<String>,
+    /// 抵扣金额
+    #[serde(default, deserialize_with = "utils::string_or_number")]
+    pub total_deduction_fee: Option<String>,
+    /// 手续费金额（负数表示扣除）
+    #[serde(default, deserialize_with = "utils::string_or_number")]
+    pub total_fee: Option<String>,
+}
+
+/// 反序列化 yes / no 或布尔值形式的开关字段
+fn yes_no<'de, D>(deserializer: D) -> std::result::Result<bool, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<serde_json::Value>::deserialize(deserializer)? {
+        Some(serde_json::Value::Bool(b)) => Ok(b),
+        Some(serde_json::Value::String(s)) => Ok(s.eq_ignore_ascii_case("yes")),
+        _ => Ok(false),
+    }
+}
+
+/// 现货成交明细（v2）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotFillResp {
+    pub user_id: Option<String>,
+    pub symbol: Option<String>,
+    pub order_id: Option<String>,
+    pub trade_id: Option<String>,
+    pub order_type: Option<OrderType>,
+    pub side: Option<Side>,
+    /// 成交价格
+    pub price_avg: Option<String>,
+    /// 成交数量（基础币）
     pub size: Option<String>,
-    // TODO: 按需补充更多字段
+    /// 成交金额（计价币）
+    pub amount: Option<String>,
+    /// 手续费明细
+    pub fee_detail: Option<FillFeeDetail>,
+    pub trade_scope: Option<TradeScope>,
+    /// 创建时间（毫秒时间戳）
+    pub c_time: Option<String>,
+    /// 更新时间（毫秒时间戳）
+    pub u_time: Option<String>,
+}
+
+impl CursorItem for SpotFillResp {
+    fn cursor_id(&self) -> Option<&str> {
+        self.trade_id.as_deref()
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        pagination::parse_ms(&self.c_time)
+    }
+}
+
+impl TimeRangeQuery for SpotFillsQuery {
+    fn set_time_range(&mut self, start_ms: i64, end_ms: i64) {
+        self.start_time = Some(start_ms);
+        self.end_time = Some(end_ms);
+        self.id_less_than = None;
+    }
 }
 
 impl BitgetClient {
     /// 查询历史成交
-    pub fn get_fills(&self, symbol: &str, order_id: &str) -> Result<String> {
+    pub fn get_fills(&self, symbol: &str, order_id: &str) -> Result<Vec<FillResp>> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("orderId".to_string(), order_id.to_string());
-        self.request(
-            crate::consts::GET,
-            "/api/mix/v1/order/fills",
-            &params,
-            false,
+        self.request_typed(crate::consts::GET, "/api/mix/v1/order/fills", &params)
+    }
+
+    /// 查询历史成交（异步版本）
+    pub async fn get_fills_async(&self, symbol: &str, order_id: &str) -> Result<Vec<FillResp>> {
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), symbol.to_string());
+        params.insert("orderId".to_string(), order_id.to_string());
+        self.request_typed_async(crate::consts::GET, "/api/mix/v1/order/fills", &params)
+            .await
+    }
+
+    /// 查询现货成交明细（v2，单页）
+    pub fn get_spot_fills(&self, query: &SpotFillsQuery) -> Result<Vec<SpotFillResp>> {
+        self.request_typed(crate::consts::GET, "/api/v2/spot/trade/fills", query)
+    }
+
+    /// 查询现货成交明细（v2，单页，异步版本）
+    pub async fn get_spot_fills_async(&self, query: &SpotFillsQuery) -> Result<Vec<SpotFillResp>> {
+        self.request_typed_async(crate::consts::GET, "/api/v2/spot/trade/fills", query)
+            .await
+    }
+
+    /// 按游标分页查询现货成交明细（v2），从最新记录向前翻页
+    pub fn spot_fills_pages(&self, query: &SpotFillsQuery) -> Result<Paginator<'_, SpotFillResp>> {
+        Paginator::new(
+            self,
+            "/api/v2/spot/trade/fills",
+            query,
+            SPOT_FILLS_PAGE_SIZE,
+        )
+    }
+
+    /// 查询任意时间区间内的现货成交明细，按最大跨度拆分窗口并分页，去重后按时间升序返回
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub fn get_spot_fills_range(
+        &self,
+        query: &SpotFillsQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<SpotFillResp>> {
+        self.get_time_range(
+            "/api/v2/spot/trade/fills",
+            query,
+            SPOT_FILLS_PAGE_SIZE,
+            SPOT_FILLS_MAX_SPAN,
+            from,
+            to,
+        )
+    }
+
+    /// 查询任意时间区间内的现货成交明细（异步版本）
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub async fn get_spot_fills_range_async(
+        &self,
+        query: &SpotFillsQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<SpotFillResp>> {
+        self.get_time_range_async(
+            "/api/v2/spot/trade/fills",
+            query,
+            SPOT_FILLS_PAGE_SIZE,
+            SPOT_FILLS_MAX_SPAN,
+            from,
+            to,
         )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    /// 文档中 GET /api/v2/spot/trade/fills 的返回示例
+    fn documented_fill() -> serde_json::Value {
+        json!({
+            "userId": "**********",
+            "symbol": "BGBUSDT",
+            "orderId": "12345678910",
+            "tradeId": "12345678910",
+            "orderType": "market",
+            "side": "buy",
+            "priceAvg": "0.11",
+            "size": "1000",
+            "amount": "110",
+            "feeDetail": {
+                "deduction": "yes",
+                "feeCoin": "BGB",
+                "totalDeductionFee": -0.112,
+                "totalFee": "-0.1120000000"
+            },
+            "tradeScope": "taker",
+            "cTime": "1695285545000",
+            "uTime": "1695285545000"
+        })
+    }
+
+    #[test]
+    fn parses_documented_fill() {
+        let fill: SpotFillResp = serde_json::from_value(documented_fill()).unwrap();
+        assert_eq!(fill.trade_id.as_deref(), Some("12345678910"));
+        assert_eq!(fill.order_type, Some(OrderType::Market));
+        assert_eq!(fill.side, Some(Side::Buy));
+        assert_eq!(fill.price_avg.as_deref(), Some("0.11"));
+        assert_eq!(fill.trade_scope, Some(TradeScope::Taker));
+        assert_eq!(fill.cursor_id(), Some("12345678910"));
+        assert_eq!(fill.timestamp_ms(), Some(1695285545000));
+
+        let fee = fill.fee_detail.unwrap();
+        assert!(fee.deduction);
+        assert_eq!(fee.fee_coin.as_deref(), Some("BGB"));
+        // 数字形式的金额按 JSON 数值转成字符串，不保留末尾的 0
+        assert_eq!(fee.total_deduction_fee.as_deref(), Some("-0.112"));
+        assert_eq!(fee.total_fee.as_deref(), Some("-0.1120000000"));
+    }
+
+    #[test]
+    fn unknown_trade_scope_and_missing_fee_detail() {
+        let mut value = documented_fill();
+        value["tradeScope"] = json!("self_trade");
+        value["feeDetail"] = json!({"deduction": "no", "feeCoin": "USDT"});
+        let fill: SpotFillResp = serde_json::from_value(value).unwrap();
+        assert_eq!(fill.trade_scope, Some(TradeScope::Unknown));
+
+        let fee = fill.fee_detail.unwrap();
+        assert!(!fee.deduction);
+        assert_eq!(fee.total_fee, None);
     }
 }
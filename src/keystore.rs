@@ -0,0 +1,490 @@
+//! 加密密钥库
+//!
+//! 将多个命名 profile 的凭证加密保存到单个文件：口令经 PBKDF2-HMAC-SHA256 派生出 256 位密钥，
+//! 凭证以 AES-256-GCM 加密，文件内容为 JSON（salt、nonce、密文均为 base64）
+
+use aes_gcm::aead::Aead;
+use aes_gcm::{Aes256Gcm, KeyInit, Nonce};
+use base64::{Engine as _, engine::general_purpose};
+use rand::RngCore;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use zeroize::Zeroizing;
+
+use crate::credentials::Credentials;
+use crate::exceptions::{BitgetError, Result};
+
+/// 密钥库文件格式版本
+pub const KEYSTORE_VERSION: u32 = 1;
+
+/// 密钥派生算法名称
+const KDF: &str = "pbkdf2-hmac-sha256";
+
+/// 默认 PBKDF2 迭代次数
+pub const DEFAULT_ITERATIONS: u32 = 600_000;
+
+/// 允许的最小 PBKDF2 迭代次数
+pub const MIN_ITERATIONS: u32 = 10_000;
+
+/// 允许的最大 PBKDF2 迭代次数（密钥库文件不可信，避免构造的文件让派生过程长时间占用 CPU）
+pub const MAX_ITERATIONS: u32 = 10_000_000;
+
+/// salt 长度（字节）
+const SALT_LEN: usize = 16;
+
+/// AES-GCM nonce 长度（字节）
+const NONCE_LEN: usize = 12;
+
+/// 密钥库文件内容
+#[derive(Debug, Serialize, Deserialize)]
+struct KeystoreFile {
+    version: u32,
+    kdf: String,
+    iterations: u32,
+    salt: String,
+    nonce: String,
+    ciphertext: String,
+}
+
+/// 加密前的凭证（仅用于序列化密钥库明文）
+#[derive(Serialize)]
+struct PlainCredentials<'a> {
+    api_key: &'a str,
+    api_secret_key: &'a str,
+    passphrase: &'a str,
+}
+
+/// 加密密钥库，保存多个命名 profile 的凭证
+#[derive(Debug, Clone)]
+pub struct Keystore {
+    /// profile 名称 -> 凭证
+    profiles: BTreeMap<String, Credentials>,
+    /// PBKDF2 迭代次数
+    iterations: u32,
+}
+
+impl Default for Keystore {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Keystore {
+    /// 创建空密钥库
+    pub fn new() -> Self {
+        Self {
+            profiles: BTreeMap::new(),
+            iterations: DEFAULT_ITERATIONS,
+        }
+    }
+
+    /// 设置 PBKDF2 迭代次数（仅影响之后的加密），须在 `MIN_ITERATIONS` 到 `MAX_ITERATIONS` 之间
+    pub fn with_iterations(mut self, iterations: u32) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// 添加或替换 profile
+    ///
+    /// # 参数
+    /// * `profile` - profile 名称
+    /// * `credentials` - 凭证
+    pub fn insert(&mut self, profile: impl Into<String>, credentials: Credentials) {
+        self.profiles.insert(profile.into(), credentials);
+    }
+
+    /// 移除 profile
+    pub fn remove(&mut self, profile: &str) -> Option<Credentials> {
+        self.profiles.remove(profile)
+    }
+
+    /// 获取指定 profile 的凭证
+    ///
+    /// # 参数
+    /// * `profile` - profile 名称
+    ///
+    /// # 返回
+    /// 返回凭证，profile 不存在时返回错误
+    pub fn profile(&self, profile: &str) -> Result<&Credentials> {
+        self.profiles
+            .get(profile)
+            .ok_or_else(|| BitgetError::Credentials(format!("未找到 profile: {}", profile)))
+    }
+
+    /// 全部 profile 名称
+    pub fn profiles(&self) -> impl Iterator<Item = &str> {
+        self.profiles.keys().map(String::as_str)
+    }
+
+    /// 打开并解密密钥库文件
+    ///
+    /// # 参数
+    /// * `path` - 密钥库文件路径
+    /// * `password` - 密钥库口令
+    ///
+    /// # 返回
+    /// 返回密钥库，文件无法读取、口令错误或文件损坏时返回错误
+    pub fn open(path: impl AsRef<Path>, password: &str) -> Result<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            BitgetError::Credentials(format!("读取密钥库 {} 失败: {}", path.display(), e))
+        })?;
+        Self::decrypt(&content, password)
+    }
+
+    /// 加密并写入密钥库文件（Unix 下文件权限为 0600）
+    ///
+    /// 先写入同目录下的临时文件并落盘，再重命名覆盖目标文件：写入中途失败不会破坏原有密钥库，
+    /// 已存在的文件也会被替换为 0600 权限的新文件
+    ///
+    /// # 参数
+    /// * `path` - 密钥库文件路径
+    /// * `password` - 密钥库口令
+    pub fn save(&self, path: impl AsRef<Path>, password: &str) -> Result<()> {
+        let path = path.as_ref();
+        let content = self.encrypt(password)?;
+
+        let tmp_path = temp_path(path);
+        let written = write_new_file(&tmp_path, content.as_bytes())
+            .and_then(|()| std::fs::rename(&tmp_path, path));
+        if let Err(e) = written {
+            let _ = std::fs::remove_file(&tmp_path);
+            return Err(BitgetError::Credentials(format!(
+                "写入密钥库 {} 失败: {}",
+                path.display(),
+                e
+            )));
+        }
+        sync_parent_dir(path);
+        Ok(())
+    }
+
+    /// 解密密钥库文件内容
+    ///
+    /// # 参数
+    /// * `content` - 密钥库文件内容（JSON）
+    /// * `password` - 密钥库口令
+    ///
+    /// # 返回
+    /// 返回密钥库，口令错误或内容损坏时返回错误
+    pub fn decrypt(content: &str, password: &str) -> Result<Self> {
+        let file: KeystoreFile = serde_json::from_str(content)
+            .map_err(|e| BitgetError::Credentials(format!("密钥库格式错误: {}", e)))?;
+        if file.version != KEYSTORE_VERSION || file.kdf != KDF {
+            return Err(BitgetError::Credentials(format!(
+                "不支持的密钥库版本: {} ({})",
+                file.version, file.kdf
+            )));
+        }
+
+        let salt = decode_field("salt", &file.salt)?;
+        let nonce = decode_field("nonce", &file.nonce)?;
+        let ciphertext = decode_field("ciphertext", &file.ciphertext)?;
+        if nonce.len() != NONCE_LEN {
+            return Err(BitgetError::Credentials(
+                "密钥库 nonce 长度错误".to_string(),
+            ));
+        }
+
+        let cipher = cipher(password, &salt, file.iterations)?;
+        let plaintext = Zeroizing::new(
+            cipher
+                .decrypt(Nonce::from_slice(&nonce), ciphertext.as_ref())
+                .map_err(|_| BitgetError::Credentials("口令错误或密钥库已损坏".to_string()))?,
+        );
+        let profiles = serde_json::from_slice(&plaintext)
+            .map_err(|e| BitgetError::Credentials(format!("密钥库内容格式错误: {}", e)))?;
+
+        Ok(Self {
+            profiles,
+            iterations: file.iterations,
+        })
+    }
+
+    /// 加密密钥库，返回文件内容（JSON）
+    ///
+    /// # 参数
+    /// * `password` - 密钥库口令
+    pub fn encrypt(&self, password: &str) -> Result<String> {
+        let mut salt = [0u8; SALT_LEN];
+        let mut nonce = [0u8; NONCE_LEN];
+        rand::thread_rng().fill_bytes(&mut salt);
+        rand::thread_rng().fill_bytes(&mut nonce);
+
+        let plain: BTreeMap<&str, PlainCredentials<'_>> = self
+            .profiles
+            .iter()
+            .map(|(name, c)| {
+                (
+                    name.as_str(),
+                    PlainCredentials {
+                        api_key: c.api_key(),
+                        api_secret_key: c.api_secret_key(),
+                        passphrase: c.passphrase(),
+                    },
+                )
+            })
+            .collect();
+        let plaintext = Zeroizing::new(
+            serde_json::to_vec(&plain)
+                .map_err(|e| BitgetError::Credentials(format!("序列化凭证失败: {}", e)))?,
+        );
+
+        let ciphertext = cipher(password, &salt, self.iterations)?
+            .encrypt(Nonce::from_slice(&nonce), plaintext.as_ref())
+            .map_err(|_| BitgetError::Credentials("加密密钥库失败".to_string()))?;
+
+        let file = KeystoreFile {
+            version: KEYSTORE_VERSION,
+            kdf: KDF.to_string(),
+            iterations: self.iterations,
+            salt: general_purpose::STANDARD.encode(salt),
+            nonce: general_purpose::STANDARD.encode(nonce),
+            ciphertext: general_purpose::STANDARD.encode(ciphertext),
+        };
+        serde_json::to_string_pretty(&file)
+            .map_err(|e| BitgetError::Credentials(format!("序列化密钥库失败: {}", e)))
+    }
+}
+
+/// 由口令派生密钥并构造 AES-256-GCM
+fn cipher(password: &str, salt: &[u8], iterations: u32) -> Result<Aes256Gcm> {
+    if !(MIN_ITERATIONS..=MAX_ITERATIONS).contains(&iterations) {
+        return Err(BitgetError::Credentials(format!(
+            "PBKDF2 迭代次数必须在 {} 到 {} 之间: {}",
+            MIN_ITERATIONS, MAX_ITERATIONS, iterations
+        )));
+    }
+    let mut key = Zeroizing::new([0u8; 32]);
+    pbkdf2::pbkdf2_hmac::<Sha256>(password.as_bytes(), salt, iterations, key.as_mut());
+    Aes256Gcm::new_from_slice(key.as_ref())
+        .map_err(|_| BitgetError::Credentials("密钥长度错误".to_string()))
+}
+
+/// 与目标文件同目录的临时文件路径
+fn temp_path(path: &Path) -> PathBuf {
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_default();
+    path.with_file_name(format!(
+        ".{}.{}.{:08x}.tmp",
+        name,
+        std::process::id(),
+        rand::random::<u32>()
+    ))
+}
+
+/// 创建新文件（Unix 下权限为 0600），写入内容并落盘
+fn write_new_file(path: &Path, content: &[u8]) -> std::io::Result<()> {
+    use std::io::Write;
+
+    let mut options = std::fs::OpenOptions::new();
+    options.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        options.mode(0o600);
+    }
+    let mut file = options.open(path)?;
+    file.write_all(content)?;
+    file.sync_all()
+}
+
+/// 将目录项的变更落盘，使重命名在断电后仍然有效（尽力而为）
+fn sync_parent_dir(path: &Path) {
+    #[cfg(unix)]
+    if let Some(dir) = path.parent() {
+        let dir = if dir.as_os_str().is_empty() {
+            Path::new(".")
+        } else {
+            dir
+        };
+        if let Ok(dir) = std::fs::File::open(dir) {
+            let _ = dir.sync_all();
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+/// 解码 base64 字段
+fn decode_field(name: &str, value: &str) -> Result<Vec<u8>> {
+    general_purpose::STANDARD
+        .decode(value)
+        .map_err(|e| BitgetError::Credentials(format!("密钥库字段 {} 解码失败: {}", name, e)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 测试中使用允许的最少迭代次数，避免拖慢测试
+    const TEST_ITERATIONS: u32 = MIN_ITERATIONS;
+
+    fn sample() -> Keystore {
+        let mut keystore = Keystore::new().with_iterations(TEST_ITERATIONS);
+        keystore.insert(
+            "main",
+            Credentials::new("main-key", "main-secret", "main-pass"),
+        );
+        keystore.insert(
+            "sub1",
+            Credentials::new("sub1-key", "sub1-secret", "sub1-pass"),
+        );
+        keystore
+    }
+
+    #[test]
+    fn encrypt_decrypt_round_trip() {
+        let content = sample().encrypt("correct horse").unwrap();
+        assert!(!content.contains("main-secret"));
+
+        let keystore = Keystore::decrypt(&content, "correct horse").unwrap();
+        assert_eq!(keystore.profiles().collect::<Vec<_>>(), ["main", "sub1"]);
+        assert_eq!(
+            keystore.profile("sub1").unwrap(),
+            &Credentials::new("sub1-key", "sub1-secret", "sub1-pass")
+        );
+        assert_eq!(keystore.iterations, TEST_ITERATIONS);
+    }
+
+    #[test]
+    fn encryption_uses_fresh_salt_and_nonce() {
+        let keystore = sample();
+        assert_ne!(
+            keystore.encrypt("pw").unwrap(),
+            keystore.encrypt("pw").unwrap()
+        );
+    }
+
+    #[test]
+    fn wrong_password_is_rejected() {
+        let content = sample().encrypt("correct horse").unwrap();
+        match Keystore::decrypt(&content, "battery staple") {
+            Err(BitgetError::Credentials(msg)) => assert_eq!(msg, "口令错误或密钥库已损坏"),
+            other => panic!("expected Credentials error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn tampered_ciphertext_is_rejected() {
+        let content = sample().encrypt("pw").unwrap();
+        let mut file: KeystoreFile = serde_json::from_str(&content).unwrap();
+        let mut ciphertext = general_purpose::STANDARD.decode(&file.ciphertext).unwrap();
+        ciphertext[0] ^= 1;
+        file.ciphertext = general_purpose::STANDARD.encode(ciphertext);
+        let content = serde_json::to_string(&file).unwrap();
+        assert!(matches!(
+            Keystore::decrypt(&content, "pw"),
+            Err(BitgetError::Credentials(_))
+        ));
+    }
+
+    #[test]
+    fn unsupported_version_is_rejected() {
+        let content = sample().encrypt("pw").unwrap();
+        let mut file: KeystoreFile = serde_json::from_str(&content).unwrap();
+        file.version = KEYSTORE_VERSION + 1;
+        let content = serde_json::to_string(&file).unwrap();
+        assert!(matches!(
+            Keystore::decrypt(&content, "pw"),
+            Err(BitgetError::Credentials(_))
+        ));
+    }
+
+    #[test]
+    fn iterations_out_of_range_are_rejected() {
+        assert!(Keystore::new().with_iterations(0).encrypt("pw").is_err());
+        assert!(
+            Keystore::new()
+                .with_iterations(MIN_ITERATIONS - 1)
+                .encrypt("pw")
+                .is_err()
+        );
+        assert!(
+            Keystore::new()
+                .with_iterations(MAX_ITERATIONS + 1)
+                .encrypt("pw")
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn crafted_iterations_are_rejected_before_deriving() {
+        let content = sample().encrypt("pw").unwrap();
+        for iterations in [0, MIN_ITERATIONS - 1, MAX_ITERATIONS + 1, u32::MAX] {
+            let mut file: KeystoreFile = serde_json::from_str(&content).unwrap();
+            file.iterations = iterations;
+            let content = serde_json::to_string(&file).unwrap();
+            let start = std::time::Instant::now();
+            match Keystore::decrypt(&content, "pw") {
+                Err(BitgetError::Credentials(msg)) => assert!(msg.contains("迭代次数"), "{msg}"),
+                other => panic!("expected Credentials error, got {other:?}"),
+            }
+            assert!(start.elapsed() < std::time::Duration::from_secs(1));
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn save_replaces_existing_file_with_private_one() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let path = std::env::temp_dir().join(format!(
+            "bitget-keystore-replace-{}.json",
+            std::process::id()
+        ));
+        std::fs::write(&path, "old").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o644)).unwrap();
+
+        sample().save(&path, "pw").unwrap();
+        let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(Keystore::open(&path, "pw").is_ok());
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn failed_rename_removes_temp_file() {
+        // 目标是目录时重命名失败，临时文件应被清理
+        let dir = std::env::temp_dir().join(format!("bitget-keystore-dir-{}", std::process::id()));
+        let target = dir.join("keystore.json");
+        std::fs::create_dir_all(&target).unwrap();
+
+        assert!(sample().save(&target, "pw").is_err());
+        let entries: Vec<_> = std::fs::read_dir(&dir).unwrap().collect();
+        assert_eq!(entries.len(), 1);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn save_and_open_file() {
+        let path =
+            std::env::temp_dir().join(format!("bitget-keystore-test-{}.json", std::process::id()));
+        sample().save(&path, "pw").unwrap();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = std::fs::metadata(&path).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+
+        let credentials = Credentials::from_keystore(&path, "pw", "main").unwrap();
+        let dir = path.parent().unwrap();
+        let prefix = format!(".{}.", path.file_name().unwrap().to_string_lossy());
+        assert!(std::fs::read_dir(dir).unwrap().all(|e| {
+            !e.unwrap()
+                .file_name()
+                .to_string_lossy()
+                .starts_with(&prefix)
+        }));
+        assert_eq!(credentials.api_key(), "main-key");
+        assert!(Credentials::from_keystore(&path, "pw", "missing").is_err());
+        assert!(Keystore::open(&path, "wrong").is_err());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -2,14 +2,36 @@ pub mod account_api;
 pub mod asset_api;
 pub mod client;
 pub mod consts;
+pub mod credentials;
 pub mod exceptions;
 pub mod fund_flow_api;
 pub mod history_api;
+pub mod keystore;
 pub mod market_api;
+pub mod middleware;
 pub mod order_api;
 pub mod order_query_api;
+pub mod pagination;
+pub mod plan_order_api;
+pub mod rate_limit;
+pub mod registry;
+pub mod response;
+pub mod retry;
+pub mod signer;
+pub mod time_sync;
+pub mod time_window;
 pub mod transfer_api;
 pub mod utils;
 pub mod ws_api;
 
-pub use client::BitgetClient;
+pub use client::{BitgetClient, ClientBuilder};
+pub use consts::Environment;
+pub use credentials::Credentials;
+pub use exceptions::{BitgetError, BitgetErrorKind};
+pub use keystore::Keystore;
+pub use middleware::{Middleware, RequestContext};
+pub use pagination::{CursorItem, Paginator};
+pub use registry::AccountRegistry;
+pub use response::{ApiResponse, MetaResponse, RawResponse, ResponseMeta};
+pub use retry::RetryPolicy;
+pub use signer::{SignatureMethod, Signer};
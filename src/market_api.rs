@@ -3,46 +3,117 @@
 //! 该模块提供了与 Bitget 行情相关的 API 接口
 //! 包括查询行情、K线、深度等功能
 
-use anyhow::Result;
 use serde::{Deserialize, Serialize};
-use serde_json::Value;
 use std::collections::BTreeMap;
 
 use crate::client::BitgetClient;
 use crate::consts;
-
-/// 行情响应结构体
+use crate::exceptions::{BitgetError, Result};
+use crate::rate_limit::EndpointLimit;
+
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[
+    EndpointLimit::ip("/api/spot/v1/market/ticker", 20),
+    EndpointLimit::ip("/api/v2/spot/market/tickers", 20),
+    EndpointLimit::ip("/api/v2/spot/market/candles", 20),
+    EndpointLimit::ip("/api/v2/spot/market/orderbook", 20),
+];
+
+/// 行情响应结构体（v1）
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TickerResp {
     /// 交易对
     pub symbol: Option<String>,
 
     /// 最新成交价
+    #[serde(alias = "close")]
     pub last: Option<String>,
 
     /// 买一价
+    #[serde(alias = "buyOne")]
     pub buy: Option<String>,
 
     /// 卖一价
+    #[serde(alias = "sellOne")]
     pub sell: Option<String>,
 
     /// 高价
+    #[serde(alias = "high24h")]
     pub high: Option<String>,
 
     /// 低价
+    #[serde(alias = "low24h")]
     pub low: Option<String>,
 
     /// 成交量
+    #[serde(alias = "baseVol")]
     pub volume: Option<String>,
 
     /// 成交额
+    #[serde(alias = "quoteVol")]
     pub quote_volume: Option<String>,
 
     /// 时间戳
+    #[serde(alias = "ts")]
     pub timestamp: Option<String>,
 }
 
+/// 行情响应结构体（v2）
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TickerV2Resp {
+    /// 交易对
+    pub symbol: Option<String>,
+
+    /// 24小时最高价
+    pub high24h: Option<String>,
+
+    /// 24小时开盘价
+    pub open: Option<String>,
+
+    /// 最新成交价
+    pub last_pr: Option<String>,
+
+    /// 24小时最低价
+    pub low24h: Option<String>,
+
+    /// 计价币成交额
+    pub quote_volume: Option<String>,
+
+    /// 基础币成交量
+    pub base_volume: Option<String>,
+
+    /// USDT 成交额
+    pub usdt_volume: Option<String>,
+
+    /// 买一价
+    pub bid_pr: Option<String>,
+
+    /// 卖一价
+    pub ask_pr: Option<String>,
+
+    /// 买一量
+    pub bid_sz: Option<String>,
+
+    /// 卖一量
+    pub ask_sz: Option<String>,
+
+    /// UTC0 开盘价
+    pub open_utc: Option<String>,
+
+    /// 时间戳
+    pub ts: Option<String>,
+
+    /// UTC0 涨跌幅
+    pub change_utc24h: Option<String>,
+
+    /// 24小时涨跌幅
+    pub change24h: Option<String>,
+}
+
 /// K线数据响应结构体
+///
+/// Bitget 以数组形式返回 K 线，字段按位置依次对应
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct CandleResp {
     /// 时间戳
@@ -60,8 +131,29 @@ pub struct CandleResp {
     /// 收盘价
     pub close: Option<String>,
 
-    /// 成交量
+    /// 成交量（基础币）
     pub volume: Option<String>,
+
+    /// USDT 成交额
+    #[serde(default)]
+    pub usdt_volume: Option<String>,
+
+    /// 成交额（计价币）
+    #[serde(default)]
+    pub quote_volume: Option<String>,
+}
+
+/// 深度数据响应结构体
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DepthResp {
+    /// 卖盘 [价格, 数量]
+    pub asks: Vec<[String; 2]>,
+
+    /// 买盘 [价格, 数量]
+    pub bids: Vec<[String; 2]>,
+
+    /// 时间戳
+    pub ts: Option<String>,
 }
 
 impl BitgetClient {
@@ -71,14 +163,14 @@ impl BitgetClient {
     /// * `symbol` - 交易对
     ///
     /// # 返回
-    /// 返回行情响应字符串或错误
-    pub fn get_ticker(&self, symbol: &str) -> Result<String> {
+    /// 返回行情数据或错误
+    pub fn get_ticker(&self, symbol: &str) -> Result<TickerResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
 
         tracing::debug!("查询现货行情(v1) - 交易对: {}", symbol);
 
-        self.request(consts::GET, "/api/spot/v1/market/ticker", &params, false)
+        self.request_typed(consts::GET, "/api/spot/v1/market/ticker", &params)
     }
 
     /// 查询现货行情（v2，推荐）
@@ -87,27 +179,17 @@ impl BitgetClient {
     /// * `symbol` - 交易对
     ///
     /// # 返回
-    /// 返回行情响应字符串或错误
-    pub fn get_ticker_v2(&self, symbol: &str) -> Result<String> {
+    /// 返回行情数据或错误
+    pub fn get_ticker_v2(&self, symbol: &str) -> Result<TickerV2Resp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
 
         tracing::debug!("查询现货行情(v2) - 交易对: {}", symbol);
 
-        let resp = self.request(consts::GET, "/api/v2/spot/market/tickers", &params, false)?;
-
-        // 解析 data 字段，返回第一个 ticker
-        let v: Value = serde_json::from_str(&resp)?;
-        if v["code"] == "00000" {
-            if let Some(arr) = v["data"].as_array() {
-                if let Some(ticker) = arr.get(0) {
-                    return Ok(ticker.to_string());
-                }
-            }
-            Err(anyhow::anyhow!("未找到行情数据: {}", resp))
-        } else {
-            Err(anyhow::anyhow!("bitget v2 ticker 错误: {}", resp))
-        }
+        let tickers: Vec<TickerV2Resp> =
+            self.request_typed(consts::GET, "/api/v2/spot/market/tickers", &params)?;
+
+        first_ticker(tickers, symbol)
     }
 
     /// 查询现货行情（v2，异步版本）
@@ -116,27 +198,18 @@ impl BitgetClient {
     /// * `symbol` - 交易对
     ///
     /// # 返回
-    /// 返回行情响应字符串或错误
-    pub async fn get_ticker_v2_async(&self, symbol: &str) -> Result<String> {
+    /// 返回行情数据或错误
+    pub async fn get_ticker_v2_async(&self, symbol: &str) -> Result<TickerV2Resp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
 
         tracing::debug!("查询现货行情(v2) - 交易对: {}", symbol);
 
-        let resp = self.request(consts::GET, "/api/v2/spot/market/tickers", &params, false)?;
-
-        // 解析 data 字段，返回第一个 ticker
-        let v: Value = serde_json::from_str(&resp)?;
-        if v["code"] == "00000" {
-            if let Some(arr) = v["data"].as_array() {
-                if let Some(ticker) = arr.get(0) {
-                    return Ok(ticker.to_string());
-                }
-            }
-            Err(anyhow::anyhow!("未找到行情数据: {}", resp))
-        } else {
-            Err(anyhow::anyhow!("bitget v2 ticker 错误: {}", resp))
-        }
+        let tickers: Vec<TickerV2Resp> = self
+            .request_typed_async(consts::GET, "/api/v2/spot/market/tickers", &params)
+            .await?;
+
+        first_ticker(tickers, symbol)
     }
 
     /// 查询K线数据
@@ -147,8 +220,13 @@ impl BitgetClient {
     /// * `limit` - 返回数量，默认100，最大500
     ///
     /// # 返回
-    /// 返回K线数据响应字符串或错误
-    pub fn get_candles(&self, symbol: &str, period: &str, limit: Option<u32>) -> Result<String> {
+    /// 返回K线数据列表或错误
+    pub fn get_candles(
+        &self,
+        symbol: &str,
+        period: &str,
+        limit: Option<u32>,
+    ) -> Result<Vec<CandleResp>> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("period".to_string(), period.to_string());
@@ -158,7 +236,7 @@ impl BitgetClient {
 
         tracing::debug!("查询K线数据 - 交易对: {}, 周期: {}", symbol, period);
 
-        self.request(consts::GET, "/api/v2/spot/market/candles", &params, false)
+        self.request_typed(consts::GET, "/api/v2/spot/market/candles", &params)
     }
 
     /// 查询K线数据（异步版本）
@@ -169,13 +247,13 @@ impl BitgetClient {
     /// * `limit` - 返回数量，默认100，最大500
     ///
     /// # 返回
-    /// 返回K线数据响应字符串或错误
+    /// 返回K线数据列表或错误
     pub async fn get_candles_async(
         &self,
         symbol: &str,
         period: &str,
         limit: Option<u32>,
-    ) -> Result<String> {
+    ) -> Result<Vec<CandleResp>> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("period".to_string(), period.to_string());
@@ -185,7 +263,8 @@ impl BitgetClient {
 
         tracing::debug!("查询K线数据 - 交易对: {}, 周期: {}", symbol, period);
 
-        self.request(consts::GET, "/api/v2/spot/market/candles", &params, false)
+        self.request_typed_async(consts::GET, "/api/v2/spot/market/candles", &params)
+            .await
     }
 
     /// 查询深度数据
@@ -195,8 +274,8 @@ impl BitgetClient {
     /// * `limit` - 返回数量，默认50，最大200
     ///
     /// # 返回
-    /// 返回深度数据响应字符串或错误
-    pub fn get_depth(&self, symbol: &str, limit: Option<u32>) -> Result<String> {
+    /// 返回深度数据或错误
+    pub fn get_depth(&self, symbol: &str, limit: Option<u32>) -> Result<DepthResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         if let Some(limit_val) = limit {
@@ -205,7 +284,7 @@ impl BitgetClient {
 
         tracing::debug!("查询深度数据 - 交易对: {}", symbol);
 
-        self.request(consts::GET, "/api/v2/spot/market/orderbook", &params, false)
+        self.request_typed(consts::GET, "/api/v2/spot/market/orderbook", &params)
     }
 
     /// 查询深度数据（异步版本）
@@ -215,8 +294,8 @@ impl BitgetClient {
     /// * `limit` - 返回数量，默认50，最大200
     ///
     /// # 返回
-    /// 返回深度数据响应字符串或错误
-    pub async fn get_depth_async(&self, symbol: &str, limit: Option<u32>) -> Result<String> {
+    /// 返回深度数据或错误
+    pub async fn get_depth_async(&self, symbol: &str, limit: Option<u32>) -> Result<DepthResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         if let Some(limit_val) = limit {
@@ -225,6 +304,15 @@ impl BitgetClient {
 
         tracing::debug!("查询深度数据 - 交易对: {}", symbol);
 
-        self.request(consts::GET, "/api/v2/spot/market/orderbook", &params, false)
+        self.request_typed_async(consts::GET, "/api/v2/spot/market/orderbook", &params)
+            .await
     }
 }
+
+/// 取 v2 tickers 响应中的第一个 ticker
+fn first_ticker(tickers: Vec<TickerV2Resp>, symbol: &str) -> Result<TickerV2Resp> {
+    tickers
+        .into_iter()
+        .next()
+        .ok_or_else(|| BitgetError::InvalidRequest(format!("未找到行情数据: {}", symbol)))
+}
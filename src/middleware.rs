@@ -0,0 +1,60 @@
+//! 请求中间件
+//!
+//! 中间件在每次发送 HTTP 请求前后被依次调用（重试时每次尝试都会调用），
+//! 可用于指标统计、审计日志、故障注入以及追加自定义请求头
+
+use reqwest::header::HeaderMap;
+use serde_json::Value;
+use std::fmt;
+use std::time::Duration;
+
+use crate::exceptions::{BitgetError, Result};
+use crate::response::RawResponse;
+
+/// 中间件可见的请求信息
+#[derive(Debug, Clone)]
+pub struct RequestContext {
+    /// 请求方法（GET/POST）
+    pub method: String,
+
+    /// 请求路径（不含查询字符串）
+    pub path: String,
+
+    /// 请求参数（GET 为查询参数，POST 为请求体）
+    pub params: Value,
+
+    /// 请求头，已包含签名相关请求头；中间件追加的请求头不参与签名。
+    /// 记录日志时请勿输出 ACCESS-KEY / ACCESS-SIGN / ACCESS-PASSPHRASE
+    pub headers: HeaderMap,
+}
+
+/// 请求中间件
+///
+/// 多个中间件按注册顺序调用 `before_send`，同样按注册顺序调用 `after_receive`
+pub trait Middleware: Send + Sync + fmt::Debug {
+    /// 请求发送前调用
+    ///
+    /// 返回错误时请求不会被发送，该错误按普通请求错误处理（同样适用重试策略）
+    ///
+    /// # 参数
+    /// * `request` - 请求信息，可修改请求头
+    fn before_send(&self, request: &mut RequestContext) -> Result<()> {
+        let _ = request;
+        Ok(())
+    }
+
+    /// 收到响应（或传输失败）后调用
+    ///
+    /// # 参数
+    /// * `request` - 请求信息
+    /// * `response` - 原始响应（任意 HTTP 状态码）或传输错误
+    /// * `latency` - 请求耗时
+    fn after_receive(
+        &self,
+        request: &RequestContext,
+        response: std::result::Result<&RawResponse, &BitgetError>,
+        latency: Duration,
+    ) {
+        let _ = (request, response, latency);
+    }
+}
@@ -2,68 +2,832 @@
 //! 示例：POST /api/mix/v1/order/place
 use crate::client::BitgetClient;
 use crate::consts;
-use anyhow::Result;
+use crate::exceptions::{BitgetError, Result};
+use crate::rate_limit::EndpointLimit;
+use crate::utils;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[
+    EndpointLimit::uid("/api/v2/spot/trade/place-order", 10),
+    EndpointLimit::uid("/api/v2/spot/trade/batch-orders", 5),
+    EndpointLimit::uid("/api/v2/spot/trade/cancel-order", 10),
+    EndpointLimit::uid("/api/v2/spot/trade/cancel-replace-order", 5),
+    EndpointLimit::uid("/api/v2/spot/trade/batch-cancel-replace-order", 5),
+    EndpointLimit::uid("/api/v2/spot/trade/batch-cancel-order", 10),
+    EndpointLimit::uid("/api/v2/spot/trade/cancel-symbol-order", 5),
+    EndpointLimit::uid("/api/mix/v1/order/cancel-order", 10),
+    EndpointLimit::uid("/api/mix/v1/order/cancel-batch-orders", 10),
+];
+
+/// 批量下单单次最多订单数
+pub const BATCH_ORDERS_MAX: usize = 50;
 
 /// 现货/合约撤单响应结构体
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct CancelOrderResp {
-    pub code: String,
-    pub msg: Option<String>,
-    pub request_time: Option<u64>,
-    pub data: Option<serde_json::Value>,
+    pub order_id: Option<String>,
+    pub client_oid: Option<String>,
+}
+
+/// 批量接口（批量撤单、批量下单）的失败项
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrderFailure {
+    pub order_id: Option<String>,
+    pub client_oid: Option<String>,
+    pub error_msg: Option<String>,
+    pub error_code: Option<String>,
 }
 
+/// 批量撤单失败项（旧名称）
+#[deprecated(note = "请使用 BatchOrderFailure")]
+pub type BatchCancelFailure = BatchOrderFailure;
+
 /// 批量撤单响应结构体
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct BatchCancelOrderResp {
-    pub code: String,
-    pub msg: Option<String>,
-    pub request_time: Option<u64>,
-    pub data: Option<serde_json::Value>,
+    #[serde(default)]
+    pub success_list: Vec<CancelOrderResp>,
+    #[serde(default)]
+    pub failure_list: Vec<BatchOrderFailure>,
+}
+
+/// 按 symbol 撤单响应结构体
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelSymbolOrderResp {
+    pub symbol: Option<String>,
+}
+
+/// 合约批量撤单失败项（v1 接口字段为下划线命名）
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesCancelFailure {
+    pub order_id: Option<String>,
+    pub err_code: Option<String>,
+    pub err_msg: Option<String>,
+}
+
+/// 合约批量撤单响应结构体
+#[derive(Debug, Clone, Deserialize)]
+pub struct FuturesBatchCancelResp {
+    pub symbol: Option<String>,
+    #[serde(default)]
+    pub order_ids: Vec<String>,
+    #[serde(default)]
+    pub fail_infos: Vec<FuturesCancelFailure>,
+}
+
+/// v2 批量撤单请求项
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCancelOrderItem {
+    pub symbol: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid: Option<String>,
+}
+
+/// v2 批量撤单请求体
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCancelOrderReq {
+    pub symbol: String,
+    pub batch_mode: String,
+    pub order_list: Vec<BatchCancelOrderItem>,
+}
+
+impl BatchCancelOrderReq {
+    /// 按订单 ID 构造同一 symbol 下的批量撤单请求
+    pub fn new(symbol: String, order_ids: Vec<String>) -> Self {
+        let order_list = order_ids
+            .into_iter()
+            .map(|order_id| BatchCancelOrderItem {
+                symbol: symbol.clone(),
+                order_id: Some(order_id),
+                client_oid: None,
+            })
+            .collect();
+        Self {
+            symbol,
+            batch_mode: "multiple".to_string(),
+            order_list,
+        }
+    }
+}
+
+/// 买卖方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Side {
+    /// 买入
+    Buy,
+    /// 卖出
+    Sell,
+    /// 未知方向（仅出现在响应中，不能用于请求）
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+/// 订单类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderType {
+    /// 限价单
+    Limit,
+    /// 市价单
+    Market,
+    /// 未知类型（仅出现在响应中，不能用于请求）
+    #[serde(other, skip_serializing)]
+    Unknown,
+}
+
+/// 订单有效方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Force {
+    /// 一直有效直至撤销
+    #[default]
+    Gtc,
+    /// 只做 maker
+    PostOnly,
+    /// 全部成交或立即撤销
+    Fok,
+    /// 立即成交并撤销剩余
+    Ioc,
+}
+
+/// 自成交保护模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StpMode {
+    /// 不启用
+    None,
+    /// 撤销 taker 单
+    CancelTaker,
+    /// 撤销 maker 单
+    CancelMaker,
+    /// 双方都撤销
+    CancelBoth,
+}
+
+/// 订单的止盈止损类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TpslType {
+    /// 普通订单
+    Normal,
+    /// 止盈止损单
+    Tpsl,
+    /// 未知类型（仅出现在响应中，不能用于请求）
+    #[serde(other, skip_serializing)]
+    Unknown,
 }
 
 /// v2 现货下单参数
 #[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
 pub struct SpotOrderV2Req {
+    /// 交易对
     pub symbol: String,
-    pub side: String,
-    pub order_type: String,
-    pub force: String,
+    /// 买卖方向
+    pub side: Side,
+    /// 订单类型
+    pub order_type: OrderType,
+    /// 订单有效方式（市价单忽略）
+    pub force: Force,
+    /// 限价单价格
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub price: Option<String>,
+    /// 数量：限价单与市价卖单为基础币数量，市价买单为计价币金额
     pub size: String,
+    /// 自定义订单 ID（携带时下单请求允许自动重试）
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub client_oid: Option<String>,
+    /// 自成交保护模式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stp_mode: Option<StpMode>,
+    /// 止盈止损类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tpsl_type: Option<TpslType>,
+    /// 止盈止损单的触发价格（`tpsl_type` 为 `Tpsl` 时必填）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<String>,
+    /// 预设止盈触发价
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset_take_profit_price: Option<String>,
+    /// 预设止盈执行价（不填为市价执行）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_take_profit_price: Option<String>,
+    /// 预设止损触发价
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset_stop_loss_price: Option<String>,
+    /// 预设止损执行价（不填为市价执行）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_stop_loss_price: Option<String>,
+    /// 请求时间（毫秒时间戳），与 `receive_window` 配合使用；批量下单时不发送
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "utils::to_string_opt"
+    )]
+    pub request_time: Option<i64>,
+    /// 有效时间窗口（毫秒），超过 `request_time + receive_window` 到达的请求被拒绝；批量下单时不发送
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        serialize_with = "utils::to_string_opt"
+    )]
+    pub receive_window: Option<i64>,
+}
+
+impl SpotOrderV2Req {
+    /// 构造限价单
+    pub fn limit(
+        symbol: impl Into<String>,
+        side: Side,
+        price: impl Into<String>,
+        size: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            symbol.into(),
+            side,
+            OrderType::Limit,
+            Some(price.into()),
+            size.into(),
+        )
+    }
+
+    /// 构造市价单（买单 `size` 为计价币金额）
+    pub fn market(symbol: impl Into<String>, side: Side, size: impl Into<String>) -> Self {
+        Self::new(symbol.into(), side, OrderType::Market, None, size.into())
+    }
+
+    /// 设置自定义订单 ID
+    pub fn with_client_oid(mut self, client_oid: impl Into<String>) -> Self {
+        self.client_oid = Some(client_oid.into());
+        self
+    }
+
+    /// 作为止盈止损单，价格触及 `trigger_price` 时委托
+    pub fn with_tpsl(mut self, trigger_price: impl Into<String>) -> Self {
+        self.tpsl_type = Some(TpslType::Tpsl);
+        self.trigger_price = Some(trigger_price.into());
+        self
+    }
+
+    /// 按订单类型构造，可选参数均为空
+    fn new(
+        symbol: String,
+        side: Side,
+        order_type: OrderType,
+        price: Option<String>,
+        size: String,
+    ) -> Self {
+        Self {
+            symbol,
+            side,
+            order_type,
+            force: Force::default(),
+            price,
+            size,
+            client_oid: None,
+            stp_mode: None,
+            tpsl_type: None,
+            trigger_price: None,
+            preset_take_profit_price: None,
+            execute_take_profit_price: None,
+            preset_stop_loss_price: None,
+            execute_stop_loss_price: None,
+            request_time: None,
+            receive_window: None,
+        }
+    }
+
+    /// 检查必填参数
+    fn validate(&self) -> Result<()> {
+        if self.side == Side::Unknown
+            || self.order_type == OrderType::Unknown
+            || self.tpsl_type == Some(TpslType::Unknown)
+        {
+            return Err(BitgetError::InvalidRequest(format!(
+                "下单的买卖方向、订单类型与止盈止损类型不能为 Unknown: {}",
+                self.symbol
+            )));
+        }
+        if self.order_type == OrderType::Limit && self.price.is_none() {
+            return Err(BitgetError::InvalidRequest(format!(
+                "限价单必须指定价格: {}",
+                self.symbol
+            )));
+        }
+        if self.tpsl_type == Some(TpslType::Tpsl) && self.trigger_price.is_none() {
+            return Err(BitgetError::InvalidRequest(format!(
+                "止盈止损单必须指定触发价格: {}",
+                self.symbol
+            )));
+        }
+        Ok(())
+    }
 }
 
 /// v2 现货下单响应
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct SpotOrderV2Resp {
     pub order_id: Option<String>,
     pub client_oid: Option<String>,
     pub msg: Option<String>,
 }
 
+/// v2 现货批量下单的订单项
+///
+/// 与 `SpotOrderV2Req` 相同，但不含批量接口不支持的 `requestTime` / `receiveWindow`
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchOrderItem {
+    /// 交易对
+    pub symbol: String,
+    /// 买卖方向
+    pub side: Side,
+    /// 订单类型
+    pub order_type: OrderType,
+    /// 订单有效方式（市价单忽略）
+    pub force: Force,
+    /// 限价单价格
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub price: Option<String>,
+    /// 数量：限价单与市价卖单为基础币数量，市价买单为计价币金额
+    pub size: String,
+    /// 自定义订单 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid: Option<String>,
+    /// 自成交保护模式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stp_mode: Option<StpMode>,
+    /// 止盈止损类型
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tpsl_type: Option<TpslType>,
+    /// 止盈止损单的触发价格（`tpsl_type` 为 `Tpsl` 时必填）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trigger_price: Option<String>,
+    /// 预设止盈触发价
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset_take_profit_price: Option<String>,
+    /// 预设止盈执行价（不填为市价执行）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_take_profit_price: Option<String>,
+    /// 预设止损触发价
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub preset_stop_loss_price: Option<String>,
+    /// 预设止损执行价（不填为市价执行）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_stop_loss_price: Option<String>,
+}
+
+impl From<SpotOrderV2Req> for BatchOrderItem {
+    fn from(order: SpotOrderV2Req) -> Self {
+        Self {
+            symbol: order.symbol,
+            side: order.side,
+            order_type: order.order_type,
+            force: order.force,
+            price: order.price,
+            size: order.size,
+            client_oid: order.client_oid,
+            stp_mode: order.stp_mode,
+            tpsl_type: order.tpsl_type,
+            trigger_price: order.trigger_price,
+            preset_take_profit_price: order.preset_take_profit_price,
+            execute_take_profit_price: order.execute_take_profit_price,
+            preset_stop_loss_price: order.preset_stop_loss_price,
+            execute_stop_loss_price: order.execute_stop_loss_price,
+        }
+    }
+}
+
+/// 批量下单模式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BatchMode {
+    /// 单币对模式：全部订单属于同一交易对
+    Single,
+    /// 多币对模式：每个订单分别指定交易对
+    Multiple,
+}
+
+/// v2 现货批量下单请求体
+///
+/// 只能通过 `single` / `multiple` 构造，以保证订单数量、交易对与 clientOid 均已校验
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchPlaceOrderReq {
+    /// 交易对（仅单币对模式）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    symbol: Option<String>,
+    /// 批量模式
+    batch_mode: BatchMode,
+    /// 订单列表
+    order_list: Vec<BatchOrderItem>,
+}
+
+impl BatchPlaceOrderReq {
+    /// 交易对（仅单币对模式）
+    pub fn symbol(&self) -> Option<&str> {
+        self.symbol.as_deref()
+    }
+
+    /// 批量模式
+    pub fn batch_mode(&self) -> BatchMode {
+        self.batch_mode
+    }
+
+    /// 订单列表（clientOid 均已补全）
+    pub fn order_list(&self) -> &[BatchOrderItem] {
+        &self.order_list
+    }
+
+    /// 构造单币对批量下单请求
+    ///
+    /// 未指定 clientOid 的订单会自动生成，便于按 clientOid 对应结果；
+    /// 订单的 `request_time` / `receive_window` 不随批量请求发送
+    ///
+    /// # 参数
+    /// * `symbol` - 交易对，所有订单必须属于该交易对
+    /// * `orders` - 订单列表，最多 50 个
+    ///
+    /// # 返回
+    /// 返回请求体，订单数量、交易对或 clientOid 不合法时返回错误
+    pub fn single(symbol: impl Into<String>, orders: Vec<SpotOrderV2Req>) -> Result<Self> {
+        let symbol = symbol.into();
+        if let Some(order) = orders.iter().find(|o| o.symbol != symbol) {
+            return Err(BitgetError::InvalidRequest(format!(
+                "单币对批量下单的交易对不一致: {} != {}",
+                order.symbol, symbol
+            )));
+        }
+        Self::build(Some(symbol), BatchMode::Single, orders)
+    }
+
+    /// 构造多币对批量下单请求
+    ///
+    /// 未指定 clientOid 的订单会自动生成，便于按 clientOid 对应结果；
+    /// 订单的 `request_time` / `receive_window` 不随批量请求发送
+    ///
+    /// # 参数
+    /// * `orders` - 订单列表，最多 50 个
+    ///
+    /// # 返回
+    /// 返回请求体，订单数量或 clientOid 不合法时返回错误
+    pub fn multiple(orders: Vec<SpotOrderV2Req>) -> Result<Self> {
+        Self::build(None, BatchMode::Multiple, orders)
+    }
+
+    /// 检查订单数量与参数，并补全 clientOid
+    fn build(
+        symbol: Option<String>,
+        batch_mode: BatchMode,
+        mut orders: Vec<SpotOrderV2Req>,
+    ) -> Result<Self> {
+        if orders.is_empty() || orders.len() > BATCH_ORDERS_MAX {
+            return Err(BitgetError::InvalidRequest(format!(
+                "批量下单数量必须在 1 到 {} 之间: {}",
+                BATCH_ORDERS_MAX,
+                orders.len()
+            )));
+        }
+
+        let mut client_oids = HashSet::new();
+        for order in &mut orders {
+            order.validate()?;
+            let client_oid = order
+                .client_oid
+                .get_or_insert_with(utils::generate_client_oid);
+            if !client_oids.insert(client_oid.clone()) {
+                return Err(BitgetError::InvalidRequest(format!(
+                    "批量下单的 clientOid 重复: {}",
+                    client_oid
+                )));
+            }
+        }
+
+        Ok(Self {
+            symbol,
+            batch_mode,
+            order_list: orders.into_iter().map(BatchOrderItem::from).collect(),
+        })
+    }
+}
+
+/// v2 现货批量下单响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchPlaceOrderResp {
+    #[serde(default)]
+    pub success_list: Vec<SpotOrderV2Resp>,
+    #[serde(default)]
+    pub failure_list: Vec<BatchOrderFailure>,
+}
+
+impl BatchPlaceOrderResp {
+    /// 按 clientOid 查找单个订单的结果
+    ///
+    /// # 返回
+    /// 成功返回 `Ok(下单结果)`，失败返回 `Err(失败原因)`，响应中不存在该 clientOid 时返回 None
+    pub fn get(
+        &self,
+        client_oid: &str,
+    ) -> Option<std::result::Result<&SpotOrderV2Resp, &BatchOrderFailure>> {
+        if let Some(placed) = self
+            .success_list
+            .iter()
+            .find(|o| o.client_oid.as_deref() == Some(client_oid))
+        {
+            return Some(Ok(placed));
+        }
+        self.failure_list
+            .iter()
+            .find(|o| o.client_oid.as_deref() == Some(client_oid))
+            .map(Err)
+    }
+
+    /// 按 clientOid 汇总全部订单的结果
+    pub fn into_results(
+        self,
+    ) -> HashMap<String, std::result::Result<SpotOrderV2Resp, BatchOrderFailure>> {
+        let placed = self
+            .success_list
+            .into_iter()
+            .filter_map(|o| Some((o.client_oid.clone()?, Ok(o))));
+        let failed = self
+            .failure_list
+            .into_iter()
+            .filter_map(|o| Some((o.client_oid.clone()?, Err(o))));
+        placed.chain(failed).collect()
+    }
+}
+
+/// v2 现货撤单改单请求体（仅支持限价单）
+#[derive(Debug, Clone)]
+pub struct CancelReplaceOrderReq {
+    /// 交易对
+    pub symbol: String,
+    /// 新订单价格
+    pub price: String,
+    /// 新订单数量
+    pub size: String,
+    /// 原订单 ID（与 `client_oid` 二选一）
+    pub order_id: Option<String>,
+    /// 原订单自定义 ID
+    pub client_oid: Option<String>,
+    /// 新订单自定义 ID（调用 `keep_client_oid()` 后不再使用，发送时取 `client_oid`）
+    pub new_client_oid: Option<String>,
+    /// 预设止盈触发价
+    pub preset_take_profit_price: Option<String>,
+    /// 预设止盈执行价（不填为市价执行）
+    pub execute_take_profit_price: Option<String>,
+    /// 预设止损触发价
+    pub preset_stop_loss_price: Option<String>,
+    /// 预设止损执行价（不填为市价执行）
+    pub execute_stop_loss_price: Option<String>,
+    /// 新订单是否沿用原订单的 clientOid
+    keep_client_oid: bool,
+}
+
+/// 撤单改单请求的序列化形式，`newClientOid` 在序列化时按 `keep_client_oid` 确定
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct CancelReplaceOrderBody<'a> {
+    symbol: &'a str,
+    price: &'a str,
+    size: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    order_id: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    client_oid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_client_oid: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preset_take_profit_price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execute_take_profit_price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    preset_stop_loss_price: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    execute_stop_loss_price: Option<&'a str>,
+}
+
+impl Serialize for CancelReplaceOrderReq {
+    fn serialize<S: serde::Serializer>(
+        &self,
+        serializer: S,
+    ) -> std::result::Result<S::Ok, S::Error> {
+        CancelReplaceOrderBody {
+            symbol: &self.symbol,
+            price: &self.price,
+            size: &self.size,
+            order_id: self.order_id.as_deref(),
+            client_oid: self.client_oid.as_deref(),
+            new_client_oid: self.resolved_new_client_oid(),
+            preset_take_profit_price: self.preset_take_profit_price.as_deref(),
+            execute_take_profit_price: self.execute_take_profit_price.as_deref(),
+            preset_stop_loss_price: self.preset_stop_loss_price.as_deref(),
+            execute_stop_loss_price: self.execute_stop_loss_price.as_deref(),
+        }
+        .serialize(serializer)
+    }
+}
+
+impl CancelReplaceOrderReq {
+    /// 按订单 ID 指定原订单
+    pub fn by_order_id(
+        symbol: impl Into<String>,
+        order_id: impl Into<String>,
+        price: impl Into<String>,
+        size: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            symbol.into(),
+            Some(order_id.into()),
+            None,
+            price.into(),
+            size.into(),
+        )
+    }
+
+    /// 按自定义订单 ID 指定原订单
+    pub fn by_client_oid(
+        symbol: impl Into<String>,
+        client_oid: impl Into<String>,
+        price: impl Into<String>,
+        size: impl Into<String>,
+    ) -> Self {
+        Self::new(
+            symbol.into(),
+            None,
+            Some(client_oid.into()),
+            price.into(),
+            size.into(),
+        )
+    }
+
+    /// 设置新订单的自定义 ID
+    pub fn with_new_client_oid(mut self, new_client_oid: impl Into<String>) -> Self {
+        self.new_client_oid = Some(new_client_oid.into());
+        self.keep_client_oid = false;
+        self
+    }
+
+    /// 新订单沿用原订单的 clientOid（原订单须按 clientOid 指定）
+    ///
+    /// 发送时才读取 `client_oid`，之后修改 `client_oid` 也会随之生效
+    pub fn keep_client_oid(mut self) -> Self {
+        self.new_client_oid = None;
+        self.keep_client_oid = true;
+        self
+    }
+
+    /// 实际发送的新订单自定义 ID
+    pub fn resolved_new_client_oid(&self) -> Option<&str> {
+        if self.keep_client_oid {
+            self.client_oid.as_deref()
+        } else {
+            self.new_client_oid.as_deref()
+        }
+    }
+
+    /// 构造请求，可选参数均为空
+    fn new(
+        symbol: String,
+        order_id: Option<String>,
+        client_oid: Option<String>,
+        price: String,
+        size: String,
+    ) -> Self {
+        Self {
+            symbol,
+            price,
+            size,
+            order_id,
+            client_oid,
+            new_client_oid: None,
+            preset_take_profit_price: None,
+            execute_take_profit_price: None,
+            preset_stop_loss_price: None,
+            execute_stop_loss_price: None,
+            keep_client_oid: false,
+        }
+    }
+
+    /// 检查原订单标识与 clientOid 选项
+    fn validate(&self) -> Result<()> {
+        if self.order_id.is_none() && self.client_oid.is_none() {
+            return Err(BitgetError::InvalidRequest(format!(
+                "撤单改单必须指定原订单的 orderId 或 clientOid: {}",
+                self.symbol
+            )));
+        }
+        if self.keep_client_oid && self.client_oid.is_none() {
+            return Err(BitgetError::InvalidRequest(format!(
+                "沿用 clientOid 时必须按 clientOid 指定原订单: {}",
+                self.symbol
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// v2 现货撤单改单结果
+///
+/// Bitget 先撤原订单再下新订单。单笔接口撤单失败时整个请求返回业务错误，原订单保持不变；
+/// 返回结果中 `success` 为 `failure` 表示撤单已成功但新订单被拒绝。
+/// 批量接口逐项返回结果，失败项只有 `msg` 说明原因，响应本身无法区分是撤单还是下单失败，
+/// 需要时请按原订单查询其状态
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CancelReplaceOrderResp {
+    /// 新订单 ID
+    pub order_id: Option<String>,
+    /// 新订单自定义 ID
+    pub client_oid: Option<String>,
+    /// 处理结果：success / failure
+    pub success: Option<String>,
+    /// 失败原因
+    pub msg: Option<String>,
+}
+
+impl CancelReplaceOrderResp {
+    /// 撤单与下单是否均已成功
+    pub fn is_success(&self) -> bool {
+        self.success.as_deref() == Some("success")
+    }
+}
+
+/// v2 现货批量撤单改单请求体
+///
+/// 只能通过 `new` 构造，以保证数量与每个改单参数均已校验
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct BatchCancelReplaceOrderReq {
+    /// 改单列表
+    order_list: Vec<CancelReplaceOrderReq>,
+}
+
+impl BatchCancelReplaceOrderReq {
+    /// 改单列表
+    pub fn order_list(&self) -> &[CancelReplaceOrderReq] {
+        &self.order_list
+    }
+
+    /// 构造批量撤单改单请求
+    ///
+    /// # 参数
+    /// * `orders` - 改单列表，最多 50 个
+    ///
+    /// # 返回
+    /// 返回请求体，数量或单个改单参数不合法时返回错误
+    pub fn new(orders: Vec<CancelReplaceOrderReq>) -> Result<Self> {
+        if orders.is_empty() || orders.len() > BATCH_ORDERS_MAX {
+            return Err(BitgetError::InvalidRequest(format!(
+                "批量撤单改单数量必须在 1 到 {} 之间: {}",
+                BATCH_ORDERS_MAX,
+                orders.len()
+            )));
+        }
+        for order in &orders {
+            order.validate()?;
+        }
+        Ok(Self { order_list: orders })
+    }
+}
+
 impl BitgetClient {
     /// v2 现货下单
-    pub fn place_spot_order_v2(&self, req: &SpotOrderV2Req) -> Result<String> {
-        let mut params = BTreeMap::new();
-        params.insert("symbol".to_string(), req.symbol.clone());
-        params.insert("side".to_string(), req.side.clone());
-        params.insert("orderType".to_string(), req.order_type.clone());
-        params.insert("force".to_string(), req.force.clone());
-        if let Some(price) = &req.price {
-            params.insert("price".to_string(), price.clone());
-        }
-        params.insert("size".to_string(), req.size.clone());
-        self.request(
-            consts::POST,
-            "/api/v2/spot/trade/place-order",
-            &params,
-            false,
-        )
+    pub fn place_spot_order_v2(&self, req: &SpotOrderV2Req) -> Result<SpotOrderV2Resp> {
+        req.validate()?;
+        self.request_typed(consts::POST, "/api/v2/spot/trade/place-order", req)
+    }
+
+    /// v2 现货下单（异步版本）
+    pub async fn place_spot_order_v2_async(&self, req: &SpotOrderV2Req) -> Result<SpotOrderV2Resp> {
+        req.validate()?;
+        self.request_typed_async(consts::POST, "/api/v2/spot/trade/place-order", req)
+            .await
+    }
+
+    /// v2 现货批量下单（单次最多 50 个订单）
+    pub fn batch_place_spot_orders(&self, req: &BatchPlaceOrderReq) -> Result<BatchPlaceOrderResp> {
+        self.request_typed(consts::POST, "/api/v2/spot/trade/batch-orders", req)
+    }
+
+    /// v2 现货批量下单（异步版本）
+    pub async fn batch_place_spot_orders_async(
+        &self,
+        req: &BatchPlaceOrderReq,
+    ) -> Result<BatchPlaceOrderResp> {
+        self.request_typed_async(consts::POST, "/api/v2/spot/trade/batch-orders", req)
+            .await
     }
 
     /// 合约撤单
@@ -72,17 +836,27 @@ impl BitgetClient {
         symbol: &str,
         order_id: &str,
         margin_coin: &str,
-    ) -> Result<String> {
+    ) -> Result<CancelOrderResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("orderId".to_string(), order_id.to_string());
         params.insert("marginCoin".to_string(), margin_coin.to_string());
-        self.request(
-            consts::POST,
-            "/api/mix/v1/order/cancel-order",
-            &params,
-            false,
-        )
+        self.request_typed(consts::POST, "/api/mix/v1/order/cancel-order", &params)
+    }
+
+    /// 合约撤单（异步版本）
+    pub async fn cancel_futures_order_async(
+        &self,
+        symbol: &str,
+        order_id: &str,
+        margin_coin: &str,
+    ) -> Result<CancelOrderResp> {
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), symbol.to_string());
+        params.insert("orderId".to_string(), order_id.to_string());
+        params.insert("marginCoin".to_string(), margin_coin.to_string());
+        self.request_typed_async(consts::POST, "/api/mix/v1/order/cancel-order", &params)
+            .await
     }
 
     /// 现货撤单(v2)
@@ -90,13 +864,69 @@ impl BitgetClient {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol);
         params.insert("orderId".to_string(), order_id);
-        let resp = self.request(
+        self.request_typed(consts::POST, "/api/v2/spot/trade/cancel-order", &params)
+    }
+
+    /// 现货撤单(v2，异步版本)
+    pub async fn cancel_spot_order_async(
+        &self,
+        symbol: String,
+        order_id: String,
+    ) -> Result<CancelOrderResp> {
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), symbol);
+        params.insert("orderId".to_string(), order_id);
+        self.request_typed_async(consts::POST, "/api/v2/spot/trade/cancel-order", &params)
+            .await
+    }
+
+    /// 现货撤单改单(v2)：撤销原订单并以新价格、数量重新下限价单
+    ///
+    /// 返回业务错误表示撤单环节失败（原订单未变）；返回结果 `is_success()` 为 false
+    /// 表示原订单已撤销但新订单未下成功
+    pub fn cancel_replace_spot_order(
+        &self,
+        req: &CancelReplaceOrderReq,
+    ) -> Result<CancelReplaceOrderResp> {
+        req.validate()?;
+        self.request_typed(consts::POST, "/api/v2/spot/trade/cancel-replace-order", req)
+    }
+
+    /// 现货撤单改单(v2，异步版本)
+    pub async fn cancel_replace_spot_order_async(
+        &self,
+        req: &CancelReplaceOrderReq,
+    ) -> Result<CancelReplaceOrderResp> {
+        req.validate()?;
+        self.request_typed_async(consts::POST, "/api/v2/spot/trade/cancel-replace-order", req)
+            .await
+    }
+
+    /// 批量现货撤单改单(v2，单次最多 50 个)，结果按请求顺序逐项返回
+    ///
+    /// 失败项无法区分撤单与下单环节，原订单是否已撤销需另行查询
+    pub fn batch_cancel_replace_spot_orders(
+        &self,
+        req: &BatchCancelReplaceOrderReq,
+    ) -> Result<Vec<CancelReplaceOrderResp>> {
+        self.request_typed(
             consts::POST,
-            "/api/v2/spot/trade/cancel-order",
-            &params,
-            false,
-        )?;
-        serde_json::from_str(&resp).map_err(|e| e.into())
+            "/api/v2/spot/trade/batch-cancel-replace-order",
+            req,
+        )
+    }
+
+    /// 批量现货撤单改单(v2，异步版本)
+    pub async fn batch_cancel_replace_spot_orders_async(
+        &self,
+        req: &BatchCancelReplaceOrderReq,
+    ) -> Result<Vec<CancelReplaceOrderResp>> {
+        self.request_typed_async(
+            consts::POST,
+            "/api/v2/spot/trade/batch-cancel-replace-order",
+            req,
+        )
+        .await
     }
 
     /// 批量现货撤单(v2)
@@ -105,35 +935,27 @@ impl BitgetClient {
         symbol: String,
         order_ids: Vec<String>,
     ) -> Result<BatchCancelOrderResp> {
-        #[derive(Serialize)]
-        struct OrderItem {
-            order_id: String,
-            symbol: String,
-            #[serde(skip_serializing_if = "Option::is_none")]
-            client_oid: Option<String>,
-        }
-
-        let order_list: Vec<OrderItem> = order_ids
-            .into_iter()
-            .map(|order_id| OrderItem {
-                order_id,
-                symbol: symbol.clone(),
-                client_oid: None,
-            })
-            .collect();
-
-        let mut params = BTreeMap::new();
-        params.insert("symbol".to_string(), symbol);
-        params.insert("batchMode".to_string(), "multiple".to_string());
-        params.insert("orderList".to_string(), serde_json::to_string(&order_list)?);
+        let params = BatchCancelOrderReq::new(symbol, order_ids);
+        self.request_typed(
+            consts::POST,
+            "/api/v2/spot/trade/batch-cancel-order",
+            &params,
+        )
+    }
 
-        let resp = self.request(
+    /// 批量现货撤单(v2，异步版本)
+    pub async fn batch_cancel_spot_order_async(
+        &self,
+        symbol: String,
+        order_ids: Vec<String>,
+    ) -> Result<BatchCancelOrderResp> {
+        let params = BatchCancelOrderReq::new(symbol, order_ids);
+        self.request_typed_async(
             consts::POST,
             "/api/v2/spot/trade/batch-cancel-order",
             &params,
-            false,
-        )?;
-        serde_json::from_str(&resp).map_err(|e| e.into())
+        )
+        .await
     }
 
     /// 批量合约撤单
@@ -142,29 +964,277 @@ impl BitgetClient {
         symbol: &str,
         order_ids: &[&str],
         margin_coin: &str,
-    ) -> Result<String> {
+    ) -> Result<FuturesBatchCancelResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("orderIds".to_string(), order_ids.join(","));
         params.insert("marginCoin".to_string(), margin_coin.to_string());
-        self.request(
+        self.request_typed(
             consts::POST,
             "/api/mix/v1/order/cancel-batch-orders",
             &params,
-            false,
         )
     }
 
+    /// 批量合约撤单（异步版本）
+    pub async fn cancel_futures_orders_async(
+        &self,
+        symbol: &str,
+        order_ids: &[&str],
+        margin_coin: &str,
+    ) -> Result<FuturesBatchCancelResp> {
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), symbol.to_string());
+        params.insert("orderIds".to_string(), order_ids.join(","));
+        params.insert("marginCoin".to_string(), margin_coin.to_string());
+        self.request_typed_async(
+            consts::POST,
+            "/api/mix/v1/order/cancel-batch-orders",
+            &params,
+        )
+        .await
+    }
+
     /// v2 批量撤销某 symbol 下所有现货订单
-    pub fn cancel_spot_symbol_orders(&self, symbol: String) -> Result<CancelOrderResp> {
+    pub fn cancel_spot_symbol_orders(&self, symbol: String) -> Result<CancelSymbolOrderResp> {
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol);
-        let resp = self.request(
+        self.request_typed(
             consts::POST,
             "/api/v2/spot/trade/cancel-symbol-order",
             &params,
-            false,
-        )?;
-        serde_json::from_str(&resp).map_err(|e| e.into())
+        )
+    }
+
+    /// v2 批量撤销某 symbol 下所有现货订单（异步版本）
+    pub async fn cancel_spot_symbol_orders_async(
+        &self,
+        symbol: String,
+    ) -> Result<CancelSymbolOrderResp> {
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), symbol);
+        self.request_typed_async(
+            consts::POST,
+            "/api/v2/spot/trade/cancel-symbol-order",
+            &params,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{Value, json};
+
+    fn timed_order() -> SpotOrderV2Req {
+        let mut order =
+            SpotOrderV2Req::limit("BTCUSDT", Side::Buy, "30000", "0.01").with_client_oid("oid-1");
+        order.request_time = Some(1_700_000_000_000);
+        order.receive_window = Some(5_000);
+        order
+    }
+
+    #[test]
+    fn request_time_and_receive_window_are_strings() {
+        let body = serde_json::to_value(timed_order()).unwrap();
+        assert_eq!(body["requestTime"], json!("1700000000000"));
+        assert_eq!(body["receiveWindow"], json!("5000"));
+
+        let body =
+            serde_json::to_value(SpotOrderV2Req::market("BTCUSDT", Side::Sell, "1")).unwrap();
+        assert!(body.get("requestTime").is_none());
+        assert!(body.get("receiveWindow").is_none());
+    }
+
+    #[test]
+    fn unknown_side_and_order_type() {
+        let side: Side = serde_json::from_value(json!("borrow")).unwrap();
+        let order_type: OrderType = serde_json::from_value(json!("limit_maker")).unwrap();
+        assert_eq!((side, order_type), (Side::Unknown, OrderType::Unknown));
+        assert!(serde_json::to_value(Side::Unknown).is_err());
+
+        let mut order = SpotOrderV2Req::market("BTCUSDT", Side::Buy, "10");
+        order.order_type = OrderType::Unknown;
+        assert!(matches!(
+            order.validate(),
+            Err(BitgetError::InvalidRequest(_))
+        ));
+        assert!(BatchPlaceOrderReq::multiple(vec![order]).is_err());
+    }
+
+    #[test]
+    fn tpsl_order_sends_trigger_price() {
+        let order =
+            SpotOrderV2Req::limit("BTCUSDT", Side::Sell, "31000", "0.01").with_tpsl("30500");
+        assert!(order.validate().is_ok());
+        let body = serde_json::to_value(&order).unwrap();
+        assert_eq!(body["tpslType"], json!("tpsl"));
+        assert_eq!(body["triggerPrice"], json!("30500"));
+
+        let req = BatchPlaceOrderReq::single("BTCUSDT", vec![order]).unwrap();
+        let body = serde_json::to_value(&req).unwrap();
+        assert_eq!(body["orderList"][0]["triggerPrice"], json!("30500"));
+
+        let body =
+            serde_json::to_value(SpotOrderV2Req::market("BTCUSDT", Side::Buy, "10")).unwrap();
+        assert!(body.get("triggerPrice").is_none());
+    }
+
+    #[test]
+    fn tpsl_order_requires_trigger_price() {
+        let mut order = SpotOrderV2Req::market("BTCUSDT", Side::Buy, "10");
+        order.tpsl_type = Some(TpslType::Tpsl);
+        assert!(matches!(
+            order.validate(),
+            Err(BitgetError::InvalidRequest(_))
+        ));
+        assert!(BatchPlaceOrderReq::multiple(vec![order.clone()]).is_err());
+
+        order.tpsl_type = Some(TpslType::Normal);
+        assert!(order.validate().is_ok());
+    }
+
+    #[test]
+    fn unknown_tpsl_type_is_parsed_but_rejected() {
+        let tpsl: TpslType = serde_json::from_value(json!("trailing")).unwrap();
+        assert_eq!(tpsl, TpslType::Unknown);
+
+        let mut order = SpotOrderV2Req::market("BTCUSDT", Side::Buy, "10");
+        order.tpsl_type = Some(tpsl);
+        assert!(matches!(
+            order.validate(),
+            Err(BitgetError::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn batch_items_omit_request_time() {
+        let req = BatchPlaceOrderReq::single("BTCUSDT", vec![timed_order()]).unwrap();
+        let body = serde_json::to_value(&req).unwrap();
+        let item = &body["orderList"][0];
+        assert_eq!(item["clientOid"], json!("oid-1"));
+        assert_eq!(item["price"], json!("30000"));
+        assert!(item.get("requestTime").is_none());
+        assert!(item.get("receiveWindow").is_none());
+        assert_eq!(body["batchMode"], Value::from("single"));
+    }
+
+    #[test]
+    fn cancel_replace_failure_is_reported() {
+        let resp: CancelReplaceOrderResp = serde_json::from_value(json!({
+            "orderId": null,
+            "clientOid": "new",
+            "success": "failure",
+            "msg": "Insufficient balance"
+        }))
+        .unwrap();
+        assert!(!resp.is_success());
+        assert_eq!(resp.msg.as_deref(), Some("Insufficient balance"));
+    }
+
+    #[test]
+    fn keep_client_oid_sends_new_client_oid() {
+        let req = CancelReplaceOrderReq::by_client_oid("BTCUSDT", "old", "30000", "0.01")
+            .keep_client_oid();
+        let body = serde_json::to_value(&req).unwrap();
+        assert_eq!(body["newClientOid"], json!("old"));
+        assert!(req.validate().is_ok());
+
+        // 调用之后修改 client_oid，发送的 newClientOid 随之变化
+        let mut req = req;
+        req.client_oid = Some("renamed".to_string());
+        req.new_client_oid = Some("ignored".to_string());
+        assert_eq!(req.resolved_new_client_oid(), Some("renamed"));
+        let body = serde_json::to_value(&req).unwrap();
+        assert_eq!(body["clientOid"], json!("renamed"));
+        assert_eq!(body["newClientOid"], json!("renamed"));
+
+        req.client_oid = None;
+        req.order_id = Some("1".to_string());
+        assert!(req.validate().is_err());
+        assert!(
+            serde_json::to_value(&req)
+                .unwrap()
+                .get("newClientOid")
+                .is_none()
+        );
+
+        let req = CancelReplaceOrderReq::by_order_id("BTCUSDT", "1", "30000", "0.01")
+            .keep_client_oid()
+            .with_new_client_oid("new");
+        assert_eq!(req.resolved_new_client_oid(), Some("new"));
+        assert!(req.validate().is_ok());
+
+        let req =
+            CancelReplaceOrderReq::by_order_id("BTCUSDT", "1", "30000", "0.01").keep_client_oid();
+        assert!(req.validate().is_err());
+    }
+
+    #[test]
+    fn cancel_replace_body_fields() {
+        let req = CancelReplaceOrderReq::by_order_id("BTCUSDT", "1", "30000", "0.01")
+            .with_new_client_oid("new");
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            json!({
+                "symbol": "BTCUSDT",
+                "price": "30000",
+                "size": "0.01",
+                "orderId": "1",
+                "newClientOid": "new"
+            })
+        );
+    }
+
+    #[test]
+    fn batch_cancel_replace_is_validated() {
+        let valid = CancelReplaceOrderReq::by_order_id("BTCUSDT", "1", "30000", "0.01");
+        let req = BatchCancelReplaceOrderReq::new(vec![valid.clone()]).unwrap();
+        assert_eq!(req.order_list().len(), 1);
+
+        let mut missing_id = valid.clone();
+        missing_id.order_id = None;
+        assert!(BatchCancelReplaceOrderReq::new(vec![valid.clone(), missing_id]).is_err());
+        assert!(BatchCancelReplaceOrderReq::new(Vec::new()).is_err());
+        assert!(BatchCancelReplaceOrderReq::new(vec![valid; BATCH_ORDERS_MAX + 1]).is_err());
+    }
+
+    #[test]
+    fn batch_constructor_fills_and_checks_client_oids() {
+        let req = BatchPlaceOrderReq::multiple(vec![
+            SpotOrderV2Req::market("BTCUSDT", Side::Buy, "10"),
+            SpotOrderV2Req::market("ETHUSDT", Side::Buy, "10"),
+        ])
+        .unwrap();
+        assert_eq!(req.batch_mode(), BatchMode::Multiple);
+        assert_eq!(req.symbol(), None);
+        assert!(req.order_list().iter().all(|o| o.client_oid.is_some()));
+
+        let duplicate = BatchPlaceOrderReq::single(
+            "BTCUSDT",
+            vec![
+                SpotOrderV2Req::market("BTCUSDT", Side::Buy, "10").with_client_oid("a"),
+                SpotOrderV2Req::market("BTCUSDT", Side::Buy, "10").with_client_oid("a"),
+            ],
+        );
+        assert!(matches!(duplicate, Err(BitgetError::InvalidRequest(_))));
+        assert!(BatchPlaceOrderReq::multiple(Vec::new()).is_err());
+    }
+
+    #[test]
+    fn batch_results_are_keyed_by_client_oid() {
+        let resp: BatchPlaceOrderResp = serde_json::from_value(json!({
+            "successList": [{"orderId": "1", "clientOid": "a"}],
+            "failureList": [{"clientOid": "b", "errorMsg": "balance", "errorCode": "43012"}]
+        }))
+        .unwrap();
+        assert_eq!(
+            resp.get("a").unwrap().unwrap().order_id.as_deref(),
+            Some("1")
+        );
+        let failure: &BatchOrderFailure = resp.get("b").unwrap().unwrap_err();
+        assert_eq!(failure.error_code.as_deref(), Some("43012"));
+        assert!(resp.get("c").is_none());
     }
 }
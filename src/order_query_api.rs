@@ -1,41 +1,342 @@
 //! Bitget 订单查询相关接口
 //! 示例：GET /api/mix/v1/order/detail
 use crate::client::BitgetClient;
-use anyhow::Result;
-use serde::Deserialize;
+use crate::exceptions::Result;
+use crate::order_api::{OrderType, Side, TpslType};
+use crate::pagination::{self, CursorItem, Paginator};
+use crate::rate_limit::EndpointLimit;
+use crate::time_window::{self, TimeRangeQuery};
+use crate::utils;
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
 use std::collections::BTreeMap;
+use std::time::Duration;
 use tracing::info;
 
-#[derive(Debug, Deserialize)]
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[
+    EndpointLimit::uid("/api/mix/v1/order/detail", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/orderInfo", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/unfilled-orders", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/history-orders", 20),
+];
+
+/// 现货历史委托每页最大条数
+pub const SPOT_HISTORY_ORDERS_PAGE_SIZE: u32 = 100;
+
+/// 现货未成交委托每页最大条数
+pub const SPOT_UNFILLED_ORDERS_PAGE_SIZE: u32 = 100;
+
+/// 现货委托查询单次允许的最大时间跨度
+pub const SPOT_ORDERS_MAX_SPAN: Duration = time_window::NINETY_DAYS;
+
+/// 合约订单详情（v1）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct OrderDetailResp {
+    pub symbol: Option<String>,
     pub order_id: Option<String>,
+    pub client_oid: Option<String>,
+    pub size: Option<String>,
+    pub price: Option<String>,
+    pub price_avg: Option<String>,
+    pub filled_qty: Option<String>,
+    pub side: Option<String>,
+    #[serde(alias = "state")]
     pub status: Option<String>,
-    // TODO: 按需补充更多字段
+    pub c_time: Option<String>,
+    pub u_time: Option<String>,
+}
+
+/// 现货订单状态（v2）
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SpotOrderStatus {
+    /// 已创建，未进入撮合
+    Init,
+    /// 未成交，已进入撮合
+    New,
+    /// 挂单中
+    Live,
+    /// 部分成交
+    PartiallyFilled,
+    /// 完全成交
+    Filled,
+    /// 已撤销
+    Cancelled,
+    /// 未知状态
+    #[serde(other)]
+    Unknown,
+}
+
+/// 下单来源
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum EnterPointSource {
+    /// 网页端
+    Web,
+    /// App
+    App,
+    /// API
+    Api,
+    /// 系统
+    Sys,
+    /// Android 客户端
+    Android,
+    /// iOS 客户端
+    Ios,
+    /// 未知来源
+    #[serde(other)]
+    Unknown,
+}
+
+/// 订单手续费明细（`feeDetail`，接口以 JSON 字符串返回）
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct OrderFeeDetail {
+    /// 手续费汇总（含抵扣）
+    #[serde(rename = "newFees")]
+    pub new_fees: Option<OrderFeeSummary>,
+    /// 按手续费币种划分的明细，key 为币种
+    #[serde(flatten)]
+    pub coins: BTreeMap<String, OrderCoinFee>,
+}
+
+/// 订单手续费汇总
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFeeSummary {
+    /// 卡券抵扣的金额
+    #[serde(rename = "c", default, deserialize_with = "utils::string_or_number")]
+    pub coupon: Option<String>,
+    /// BGB 抵扣的金额
+    #[serde(rename = "d", default, deserialize_with = "utils::string_or_number")]
+    pub bgb_deduction: Option<String>,
+    /// 抵扣后以交易币种扣除的金额
+    #[serde(rename = "r", default, deserialize_with = "utils::string_or_number")]
+    pub remaining: Option<String>,
+    /// 手续费总额
+    #[serde(rename = "t", default, deserialize_with = "utils::string_or_number")]
+    pub total: Option<String>,
+    /// 是否使用抵扣
+    pub deduction: Option<bool>,
+    /// 抵扣总额
+    #[serde(default, deserialize_with = "utils::string_or_number")]
+    pub total_deduction_fee: Option<String>,
+}
+
+/// 单个币种的订单手续费
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderCoinFee {
+    /// 是否使用抵扣
+    pub deduction: Option<bool>,
+    /// 手续费币种
+    pub fee_coin_code: Option<String>,
+    /// 抵扣金额
+    #[serde(default, deserialize_with = "utils::string_or_number")]
+    pub total_deduction_fee: Option<String>,
+    /// 手续费金额（负数表示扣除）
+    #[serde(default, deserialize_with = "utils::string_or_number")]
+    pub total_fee: Option<String>,
+}
+
+/// 现货订单（v2）
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotOrderResp {
+    pub user_id: Option<String>,
+    pub symbol: Option<String>,
+    pub order_id: Option<String>,
+    pub client_oid: Option<String>,
+    /// 委托价格
+    pub price: Option<String>,
+    /// 委托数量（市价买单为计价币金额）
+    pub size: Option<String>,
+    pub order_type: Option<OrderType>,
+    pub side: Option<Side>,
+    pub status: Option<SpotOrderStatus>,
+    /// 成交均价
+    pub price_avg: Option<String>,
+    /// 已成交数量（基础币）
+    pub base_volume: Option<String>,
+    /// 已成交金额（计价币）
+    pub quote_volume: Option<String>,
+    pub enter_point_source: Option<EnterPointSource>,
+    /// 手续费明细
+    #[serde(default, deserialize_with = "fee_detail")]
+    pub fee_detail: Option<OrderFeeDetail>,
+    pub order_source: Option<String>,
+    pub tpsl_type: Option<TpslType>,
+    /// 止盈止损单的触发价格
+    pub trigger_price: Option<String>,
+    /// 撤单原因
+    pub cancel_reason: Option<String>,
+    /// 创建时间（毫秒时间戳）
+    pub c_time: Option<String>,
+    /// 更新时间（毫秒时间戳）
+    pub u_time: Option<String>,
+}
+
+/// 反序列化订单手续费明细：接口返回 JSON 字符串（可能为空字符串），也兼容对象形式
+fn fee_detail<'de, D>(deserializer: D) -> std::result::Result<Option<OrderFeeDetail>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let parsed = match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => return Ok(None),
+        Some(Value::String(s)) if s.trim().is_empty() => return Ok(None),
+        Some(Value::String(s)) => serde_json::from_str(&s),
+        Some(other) => serde_json::from_value(other),
+    };
+    parsed.map(Some).map_err(serde::de::Error::custom)
+}
+
+impl CursorItem for SpotOrderResp {
+    fn cursor_id(&self) -> Option<&str> {
+        self.order_id.as_deref()
+    }
+
+    fn timestamp_ms(&self) -> Option<i64> {
+        pagination::parse_ms(&self.c_time)
+    }
+}
+
+/// 现货历史委托查询参数（v2）
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotHistoryOrdersQuery {
+    /// 交易对，不填查询全部
+    pub symbol: Option<String>,
+    /// 订单 ID
+    pub order_id: Option<String>,
+    /// 开始时间（毫秒时间戳）
+    pub start_time: Option<i64>,
+    /// 结束时间（毫秒时间戳）
+    pub end_time: Option<i64>,
+    /// 每页条数，最大 100
+    pub limit: Option<u32>,
+    /// 游标：返回 orderId 小于该值的记录
+    pub id_less_than: Option<String>,
+}
+
+/// 现货未成交委托查询参数（v2，与历史委托参数相同）
+pub type SpotUnfilledOrdersQuery = SpotHistoryOrdersQuery;
+
+impl TimeRangeQuery for SpotHistoryOrdersQuery {
+    fn set_time_range(&mut self, start_ms: i64, end_ms: i64) {
+        self.start_time = Some(start_ms);
+        self.end_time = Some(end_ms);
+        self.id_less_than = None;
+    }
 }
 
 impl BitgetClient {
     /// 查询订单详情（合约）
-    pub fn get_order_detail(&self, symbol: &str, order_id: &str) -> Result<String> {
+    pub fn get_order_detail(&self, symbol: &str, order_id: &str) -> Result<OrderDetailResp> {
         info!("查询订单详情 symbol={}, order_id={}", symbol, order_id);
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
         params.insert("orderId".to_string(), order_id.to_string());
-        self.request(
+        self.request_typed(crate::consts::GET, "/api/mix/v1/order/detail", &params)
+    }
+
+    /// 查询订单详情（合约，异步版本）
+    pub async fn get_order_detail_async(
+        &self,
+        symbol: &str,
+        order_id: &str,
+    ) -> Result<OrderDetailResp> {
+        info!("查询订单详情 symbol={}, order_id={}", symbol, order_id);
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), symbol.to_string());
+        params.insert("orderId".to_string(), order_id.to_string());
+        self.request_typed_async(crate::consts::GET, "/api/mix/v1/order/detail", &params)
+            .await
+    }
+
+    /// 按订单 ID 查询现货订单详情（v2），订单不存在时返回 None
+    pub fn get_spot_order_info(&self, order_id: &str) -> Result<Option<SpotOrderResp>> {
+        info!("查询现货订单详情 order_id={}", order_id);
+        let mut params = BTreeMap::new();
+        params.insert("orderId".to_string(), order_id.to_string());
+        let orders: Vec<SpotOrderResp> =
+            self.request_typed(crate::consts::GET, "/api/v2/spot/trade/orderInfo", &params)?;
+        Ok(orders.into_iter().next())
+    }
+
+    /// 按订单 ID 查询现货订单详情（v2，异步版本）
+    pub async fn get_spot_order_info_async(&self, order_id: &str) -> Result<Option<SpotOrderResp>> {
+        info!("查询现货订单详情 order_id={}", order_id);
+        let mut params = BTreeMap::new();
+        params.insert("orderId".to_string(), order_id.to_string());
+        let orders: Vec<SpotOrderResp> = self
+            .request_typed_async(crate::consts::GET, "/api/v2/spot/trade/orderInfo", &params)
+            .await?;
+        Ok(orders.into_iter().next())
+    }
+
+    /// 按自定义订单 ID 查询现货订单详情（v2），订单不存在时返回 None
+    pub fn get_spot_order_info_by_client_oid(
+        &self,
+        client_oid: &str,
+    ) -> Result<Option<SpotOrderResp>> {
+        info!("查询现货订单详情 client_oid={}", client_oid);
+        let mut params = BTreeMap::new();
+        params.insert("clientOid".to_string(), client_oid.to_string());
+        let orders: Vec<SpotOrderResp> =
+            self.request_typed(crate::consts::GET, "/api/v2/spot/trade/orderInfo", &params)?;
+        Ok(orders.into_iter().next())
+    }
+
+    /// 按自定义订单 ID 查询现货订单详情（v2，异步版本）
+    pub async fn get_spot_order_info_by_client_oid_async(
+        &self,
+        client_oid: &str,
+    ) -> Result<Option<SpotOrderResp>> {
+        info!("查询现货订单详情 client_oid={}", client_oid);
+        let mut params = BTreeMap::new();
+        params.insert("clientOid".to_string(), client_oid.to_string());
+        let orders: Vec<SpotOrderResp> = self
+            .request_typed_async(crate::consts::GET, "/api/v2/spot/trade/orderInfo", &params)
+            .await?;
+        Ok(orders.into_iter().next())
+    }
+
+    /// 查询现货未成交订单（活跃订单，v2）
+    pub fn get_spot_unfilled_orders(
+        &self,
+        symbol: &str,
+        start_time: Option<u64>,
+        end_time: Option<u64>,
+        limit: Option<u32>,
+    ) -> Result<Vec<SpotOrderResp>> {
+        info!("查询现货未成交订单 symbol={}", symbol);
+        let mut params = BTreeMap::new();
+        params.insert("symbol".to_string(), symbol.to_string());
+        if let Some(start) = start_time {
+            params.insert("startTime".to_string(), start.to_string());
+        }
+        if let Some(end) = end_time {
+            params.insert("endTime".to_string(), end.to_string());
+        }
+        if let Some(lim) = limit {
+            params.insert("limit".to_string(), lim.to_string());
+        }
+        self.request_typed(
             crate::consts::GET,
-            "/api/mix/v1/order/detail",
+            "/api/v2/spot/trade/unfilled-orders",
             &params,
-            false,
         )
     }
 
-    /// 查询现货未成交订单（活跃订单，v2）
-    pub fn get_spot_unfilled_orders(
+    /// 查询现货未成交订单（活跃订单，v2，异步版本）
+    pub async fn get_spot_unfilled_orders_async(
         &self,
         symbol: &str,
         start_time: Option<u64>,
         end_time: Option<u64>,
         limit: Option<u32>,
-    ) -> Result<String> {
+    ) -> Result<Vec<SpotOrderResp>> {
         info!("查询现货未成交订单 symbol={}", symbol);
         let mut params = BTreeMap::new();
         params.insert("symbol".to_string(), symbol.to_string());
@@ -48,11 +349,219 @@ impl BitgetClient {
         if let Some(lim) = limit {
             params.insert("limit".to_string(), lim.to_string());
         }
-        self.request(
+        self.request_typed_async(
             crate::consts::GET,
             "/api/v2/spot/trade/unfilled-orders",
             &params,
-            false,
         )
+        .await
+    }
+
+    /// 查询现货历史委托（v2，单页）
+    pub fn get_spot_history_orders(
+        &self,
+        query: &SpotHistoryOrdersQuery,
+    ) -> Result<Vec<SpotOrderResp>> {
+        info!("查询现货历史委托 symbol={:?}", query.symbol);
+        self.request_typed(
+            crate::consts::GET,
+            "/api/v2/spot/trade/history-orders",
+            query,
+        )
+    }
+
+    /// 查询现货历史委托（v2，单页，异步版本）
+    pub async fn get_spot_history_orders_async(
+        &self,
+        query: &SpotHistoryOrdersQuery,
+    ) -> Result<Vec<SpotOrderResp>> {
+        info!("查询现货历史委托 symbol={:?}", query.symbol);
+        self.request_typed_async(
+            crate::consts::GET,
+            "/api/v2/spot/trade/history-orders",
+            query,
+        )
+        .await
+    }
+
+    /// 按游标分页查询现货历史委托（v2），从最新记录向前翻页
+    pub fn spot_history_orders_pages(
+        &self,
+        query: &SpotHistoryOrdersQuery,
+    ) -> Result<Paginator<'_, SpotOrderResp>> {
+        Paginator::new(
+            self,
+            "/api/v2/spot/trade/history-orders",
+            query,
+            SPOT_HISTORY_ORDERS_PAGE_SIZE,
+        )
+    }
+
+    /// 查询任意时间区间内的现货历史委托，按最大跨度拆分窗口并分页，去重后按时间升序返回
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub fn get_spot_history_orders_range(
+        &self,
+        query: &SpotHistoryOrdersQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<SpotOrderResp>> {
+        self.get_time_range(
+            "/api/v2/spot/trade/history-orders",
+            query,
+            SPOT_HISTORY_ORDERS_PAGE_SIZE,
+            SPOT_ORDERS_MAX_SPAN,
+            from,
+            to,
+        )
+    }
+
+    /// 查询任意时间区间内的现货历史委托（异步版本）
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub async fn get_spot_history_orders_range_async(
+        &self,
+        query: &SpotHistoryOrdersQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<SpotOrderResp>> {
+        self.get_time_range_async(
+            "/api/v2/spot/trade/history-orders",
+            query,
+            SPOT_HISTORY_ORDERS_PAGE_SIZE,
+            SPOT_ORDERS_MAX_SPAN,
+            from,
+            to,
+        )
+        .await
+    }
+
+    /// 查询任意时间区间内的现货未成交委托，按最大跨度拆分窗口并分页，去重后按时间升序返回
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub fn get_spot_unfilled_orders_range(
+        &self,
+        query: &SpotUnfilledOrdersQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<SpotOrderResp>> {
+        self.get_time_range(
+            "/api/v2/spot/trade/unfilled-orders",
+            query,
+            SPOT_UNFILLED_ORDERS_PAGE_SIZE,
+            SPOT_ORDERS_MAX_SPAN,
+            from,
+            to,
+        )
+    }
+
+    /// 查询任意时间区间内的现货未成交委托（异步版本）
+    ///
+    /// # 参数
+    /// * `query` - 查询参数（时间范围与游标会被覆盖）
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    pub async fn get_spot_unfilled_orders_range_async(
+        &self,
+        query: &SpotUnfilledOrdersQuery,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<SpotOrderResp>> {
+        self.get_time_range_async(
+            "/api/v2/spot/trade/unfilled-orders",
+            query,
+            SPOT_UNFILLED_ORDERS_PAGE_SIZE,
+            SPOT_ORDERS_MAX_SPAN,
+            from,
+            to,
+        )
+        .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Bitget 文档中 `GET /api/v2/spot/trade/orderInfo` 的响应示例
+    const ORDER_INFO_SAMPLE: &str = r#"{
+        "userId": "**********",
+        "symbol": "BTCUSDT",
+        "orderId": "121211212122",
+        "clientOid": "121211212122",
+        "price": "0",
+        "size": "20.0000000000000000",
+        "orderType": "market",
+        "side": "buy",
+        "status": "filled",
+        "priceAvg": "0",
+        "baseVolume": "0",
+        "quoteVolume": "0",
+        "enterPointSource": "API",
+        "feeDetail": "{\"newFees\":{\"c\":0,\"d\":0,\"deduction\":false,\"r\":-0.112,\"t\":-0.112,\"totalDeductionFee\":0},\"USDT\":{\"deduction\":false,\"feeCoinCode\":\"USDT\",\"totalDeductionFee\":0,\"totalFee\":-0.1120000000}}",
+        "orderSource": "normal",
+        "cTime": "1622697148",
+        "uTime": "1622697148",
+        "tpslType": "normal",
+        "cancelReason": "",
+        "triggerPrice": null
+    }"#;
+
+    #[test]
+    fn parses_documented_order_info() {
+        let order: SpotOrderResp = serde_json::from_str(ORDER_INFO_SAMPLE).unwrap();
+        assert_eq!(order.order_type, Some(OrderType::Market));
+        assert_eq!(order.side, Some(Side::Buy));
+        assert_eq!(order.status, Some(SpotOrderStatus::Filled));
+        assert_eq!(order.enter_point_source, Some(EnterPointSource::Api));
+        assert_eq!(order.tpsl_type, Some(TpslType::Normal));
+
+        let fee = order.fee_detail.unwrap();
+        let summary = fee.new_fees.unwrap();
+        assert_eq!(summary.total.as_deref(), Some("-0.112"));
+        assert_eq!(summary.remaining.as_deref(), Some("-0.112"));
+        assert_eq!(summary.coupon.as_deref(), Some("0"));
+        assert_eq!(summary.deduction, Some(false));
+
+        let usdt = &fee.coins["USDT"];
+        assert_eq!(usdt.fee_coin_code.as_deref(), Some("USDT"));
+        // feeDetail 内的数值为 JSON 数字，转为字符串时不保留末尾的 0
+        assert_eq!(usdt.total_fee.as_deref(), Some("-0.112"));
+        assert_eq!(fee.coins.len(), 1);
+    }
+
+    #[test]
+    fn empty_or_object_fee_detail() {
+        let order: SpotOrderResp =
+            serde_json::from_str(r#"{"orderId": "1", "feeDetail": ""}"#).unwrap();
+        assert!(order.fee_detail.is_none());
+
+        let order: SpotOrderResp = serde_json::from_str(
+            r#"{"orderId": "1", "feeDetail": {"BGB": {"feeCoinCode": "BGB", "totalFee": "-0.01"}}}"#,
+        )
+        .unwrap();
+        let fee = order.fee_detail.unwrap();
+        assert!(fee.new_fees.is_none());
+        assert_eq!(fee.coins["BGB"].total_fee.as_deref(), Some("-0.01"));
+    }
+
+    #[test]
+    fn unknown_enum_values_do_not_fail() {
+        let order: SpotOrderResp = serde_json::from_str(
+            r#"{"orderId": "1", "orderType": "limit_maker", "side": "unknown_side", "status": "expired"}"#,
+        )
+        .unwrap();
+        assert_eq!(order.order_type, Some(OrderType::Unknown));
+        assert_eq!(order.side, Some(Side::Unknown));
+        assert_eq!(order.status, Some(SpotOrderStatus::Unknown));
     }
 }
@@ -0,0 +1,367 @@
+//! 游标分页
+//!
+//! Bitget 历史类接口按 `idLessThan`（上一页最后一条记录的 ID）与 `limit` 向更早的记录翻页。
+//! `Paginator` 同时实现 `Iterator`（同步）并可转换为 `Stream`（异步），逐页返回记录，
+//! 在最后一页（不足 `limit` 条）或越过 `startTime` 时停止；每一页请求都经过客户端限频
+
+use futures_util::Stream;
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::collections::BTreeMap;
+use std::marker::PhantomData;
+
+use crate::client::BitgetClient;
+use crate::consts;
+use crate::exceptions::Result;
+use crate::utils;
+
+/// 默认游标参数名
+pub const ID_LESS_THAN: &str = "idLessThan";
+
+/// 可按游标分页的记录
+pub trait CursorItem {
+    /// 记录 ID，作为下一页的游标
+    fn cursor_id(&self) -> Option<&str>;
+
+    /// 记录时间（毫秒时间戳），用于在时间下界处停止
+    fn timestamp_ms(&self) -> Option<i64>;
+}
+
+/// 游标分页器
+///
+/// 每次迭代返回一页记录；遇到错误时返回该错误并停止
+#[derive(Debug, Clone)]
+pub struct Paginator<'a, T> {
+    /// 发送请求的客户端
+    client: &'a BitgetClient,
+    /// 接口路径
+    path: &'static str,
+    /// 查询参数（含游标）
+    params: BTreeMap<String, String>,
+    /// 游标参数名
+    cursor_param: &'static str,
+    /// 每页条数
+    limit: usize,
+    /// 时间下界（毫秒），早于该时间的记录被丢弃并停止翻页
+    start_time: Option<i64>,
+    /// 是否已取完
+    done: bool,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: CursorItem + DeserializeOwned> Paginator<'a, T> {
+    /// 创建分页器
+    ///
+    /// # 参数
+    /// * `client` - 客户端
+    /// * `path` - 接口路径
+    /// * `query` - 查询参数，未指定 `limit` 时使用 `page_size`
+    /// * `page_size` - 接口允许的每页最大条数，`limit` 超过该值时按该值请求
+    pub fn new<Q: Serialize + ?Sized>(
+        client: &'a BitgetClient,
+        path: &'static str,
+        query: &Q,
+        page_size: u32,
+    ) -> Result<Self> {
+        let mut params = utils::to_query_params(query)?;
+        // 接口会把超出上限的 limit 截断到上限，若不在此截断，满页判断永远不成立，只能取到第一页
+        let page_size = page_size.max(1);
+        let limit = params
+            .get("limit")
+            .and_then(|v| v.parse::<u32>().ok())
+            .filter(|&limit| limit > 0)
+            .map_or(page_size, |limit| limit.min(page_size));
+        params.insert("limit".to_string(), limit.to_string());
+        let limit = limit as usize;
+        let start_time = params.get("startTime").and_then(|v| v.parse().ok());
+
+        Ok(Self {
+            client,
+            path,
+            params,
+            cursor_param: ID_LESS_THAN,
+            limit,
+            start_time,
+            done: false,
+            _marker: PhantomData,
+        })
+    }
+
+    /// 使用其他游标参数名（如 `endId`）
+    pub fn with_cursor_param(mut self, cursor_param: &'static str) -> Self {
+        self.cursor_param = cursor_param;
+        self
+    }
+
+    /// 请求下一页
+    ///
+    /// # 返回
+    /// 返回下一页记录，已取完时返回 None
+    pub fn next_page(&mut self) -> Option<Result<Vec<T>>> {
+        if self.done {
+            return None;
+        }
+        let page = self
+            .client
+            .request_typed(consts::GET, self.path, &self.params);
+        self.advance(page)
+    }
+
+    /// 请求下一页（异步版本）
+    ///
+    /// # 返回
+    /// 返回下一页记录，已取完时返回 None
+    pub async fn next_page_async(&mut self) -> Option<Result<Vec<T>>> {
+        if self.done {
+            return None;
+        }
+        let page = self
+            .client
+            .request_typed_async(consts::GET, self.path, &self.params)
+            .await;
+        self.advance(page)
+    }
+
+    /// 转换为逐页返回记录的异步流
+    pub fn into_stream(self) -> impl Stream<Item = Result<Vec<T>>> + 'a
+    where
+        T: 'a,
+    {
+        futures_util::stream::unfold(self, |mut paginator| async move {
+            let page = paginator.next_page_async().await?;
+            Some((page, paginator))
+        })
+    }
+
+    /// 取出全部记录
+    ///
+    /// # 返回
+    /// 返回全部页的记录（按接口返回顺序）或第一个错误
+    pub fn collect_all(self) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        for page in self {
+            items.extend(page?);
+        }
+        Ok(items)
+    }
+
+    /// 取出全部记录（异步版本）
+    ///
+    /// # 返回
+    /// 返回全部页的记录（按接口返回顺序）或第一个错误
+    pub async fn collect_all_async(mut self) -> Result<Vec<T>> {
+        let mut items = Vec::new();
+        while let Some(page) = self.next_page_async().await {
+            items.extend(page?);
+        }
+        Ok(items)
+    }
+
+    /// 根据本页结果更新游标，判断是否还有下一页
+    fn advance(&mut self, page: Result<Vec<T>>) -> Option<Result<Vec<T>>> {
+        let mut items = match page {
+            Ok(items) => items,
+            Err(e) => {
+                self.done = true;
+                return Some(Err(e));
+            }
+        };
+
+        let full = items.len() >= self.limit;
+        let cursor = items
+            .last()
+            .and_then(|item| item.cursor_id())
+            .map(str::to_string);
+
+        let received = items.len();
+        if let Some(start) = self.start_time {
+            items.retain(|item| item.timestamp_ms().is_none_or(|ts| ts >= start));
+        }
+        let crossed_start = items.len() < received;
+
+        match cursor {
+            Some(cursor)
+                if full
+                    && !crossed_start
+                    && self.params.get(self.cursor_param) != Some(&cursor) =>
+            {
+                self.params.insert(self.cursor_param.to_string(), cursor);
+            }
+            _ => self.done = true,
+        }
+
+        if items.is_empty() && self.done {
+            return None;
+        }
+        Some(Ok(items))
+    }
+}
+
+impl<T: CursorItem + DeserializeOwned> Iterator for Paginator<'_, T> {
+    type Item = Result<Vec<T>>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.next_page()
+    }
+}
+
+/// 解析字符串形式的毫秒时间戳
+pub(crate) fn parse_ms(value: &Option<String>) -> Option<i64> {
+    value.as_deref().and_then(|v| v.parse().ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize, PartialEq)]
+    struct Record {
+        id: String,
+        ts: i64,
+    }
+
+    impl CursorItem for Record {
+        fn cursor_id(&self) -> Option<&str> {
+            Some(&self.id)
+        }
+
+        fn timestamp_ms(&self) -> Option<i64> {
+            Some(self.ts)
+        }
+    }
+
+    fn records(ids: &[(u32, i64)]) -> Vec<Record> {
+        ids.iter()
+            .map(|&(id, ts)| Record {
+                id: id.to_string(),
+                ts,
+            })
+            .collect()
+    }
+
+    fn client() -> BitgetClient {
+        BitgetClient::builder().public_only().build().unwrap()
+    }
+
+    fn new_paginator<'a>(
+        client: &'a BitgetClient,
+        query: &[(&str, &str)],
+        page_size: u32,
+    ) -> Paginator<'a, Record> {
+        let query: BTreeMap<&str, &str> = query.iter().copied().collect();
+        Paginator::new(client, "/test", &query, page_size).unwrap()
+    }
+
+    #[test]
+    fn limit_defaults_to_page_size() {
+        let client = client();
+        let paginator = new_paginator(&client, &[], 100);
+        assert_eq!(paginator.limit, 100);
+        assert_eq!(paginator.params["limit"], "100");
+    }
+
+    #[test]
+    fn limit_is_clamped_to_page_size() {
+        let client = client();
+        let paginator = new_paginator(&client, &[("limit", "500")], 100);
+        assert_eq!(paginator.limit, 100);
+        assert_eq!(paginator.params["limit"], "100");
+
+        let paginator = new_paginator(&client, &[("limit", "20")], 100);
+        assert_eq!(paginator.limit, 20);
+        assert_eq!(paginator.params["limit"], "20");
+
+        let paginator = new_paginator(&client, &[("limit", "0")], 100);
+        assert_eq!(paginator.limit, 100);
+    }
+
+    #[test]
+    fn full_page_advances_cursor() {
+        let client = client();
+        let mut paginator = new_paginator(&client, &[("limit", "2")], 100);
+
+        let page = paginator.advance(Ok(records(&[(9, 900), (8, 800)])));
+        assert_eq!(page.unwrap().unwrap().len(), 2);
+        assert!(!paginator.done);
+        assert_eq!(paginator.params[ID_LESS_THAN], "8");
+
+        let page = paginator.advance(Ok(records(&[(7, 700), (6, 600)])));
+        assert_eq!(page.unwrap().unwrap().len(), 2);
+        assert_eq!(paginator.params[ID_LESS_THAN], "6");
+    }
+
+    #[test]
+    fn custom_cursor_param_is_used() {
+        let client = client();
+        let mut paginator =
+            new_paginator(&client, &[("limit", "1")], 100).with_cursor_param("endId");
+        paginator.advance(Ok(records(&[(5, 500)])));
+        assert_eq!(paginator.params["endId"], "5");
+        assert!(!paginator.params.contains_key(ID_LESS_THAN));
+    }
+
+    #[test]
+    fn short_page_stops() {
+        let client = client();
+        let mut paginator = new_paginator(&client, &[("limit", "3")], 100);
+        let page = paginator.advance(Ok(records(&[(9, 900), (8, 800)])));
+        assert_eq!(page.unwrap().unwrap().len(), 2);
+        assert!(paginator.done);
+    }
+
+    #[test]
+    fn empty_page_ends_iteration() {
+        let client = client();
+        let mut paginator = new_paginator(&client, &[("limit", "2")], 100);
+        assert!(paginator.advance(Ok(Vec::new())).is_none());
+        assert!(paginator.done);
+        assert!(paginator.next_page().is_none());
+    }
+
+    #[test]
+    fn repeated_cursor_stops() {
+        let client = client();
+        let mut paginator = new_paginator(&client, &[("limit", "1")], 100);
+        paginator.advance(Ok(records(&[(5, 500)])));
+        assert!(!paginator.done);
+        // 接口忽略游标、重复返回同一页时不再翻页
+        let page = paginator.advance(Ok(records(&[(5, 500)])));
+        assert_eq!(page.unwrap().unwrap().len(), 1);
+        assert!(paginator.done);
+    }
+
+    #[test]
+    fn error_stops() {
+        let client = client();
+        let mut paginator = new_paginator(&client, &[], 100);
+        let page = paginator.advance(Err(crate::exceptions::BitgetError::InvalidRequest(
+            "boom".to_string(),
+        )));
+        assert!(page.unwrap().is_err());
+        assert!(paginator.done);
+    }
+
+    #[test]
+    fn start_time_cuts_off_older_records() {
+        let client = client();
+        let mut paginator = new_paginator(&client, &[("limit", "3"), ("startTime", "700")], 100);
+
+        let page = paginator.advance(Ok(records(&[(9, 900), (8, 800), (7, 700)])));
+        assert_eq!(page.unwrap().unwrap().len(), 3);
+        assert!(!paginator.done);
+
+        let page = paginator.advance(Ok(records(&[(6, 650), (5, 600), (4, 500)])));
+        assert!(page.is_none());
+        assert!(paginator.done);
+    }
+
+    #[test]
+    fn start_time_keeps_records_on_boundary_page() {
+        let client = client();
+        let mut paginator = new_paginator(&client, &[("limit", "3"), ("startTime", "700")], 100);
+        let page = paginator.advance(Ok(records(&[(8, 800), (7, 700), (6, 650)])));
+        assert_eq!(page.unwrap().unwrap(), records(&[(8, 800), (7, 700)]));
+        assert!(paginator.done);
+    }
+}
@@ -0,0 +1,798 @@
+//! Bitget 现货计划委托（条件单）相关接口
+//! 示例：POST /api/v2/spot/trade/place-plan-order
+use crate::client::BitgetClient;
+use crate::consts;
+use crate::exceptions::{BitgetError, Result};
+use crate::order_api::{Force, OrderType, Side, StpMode};
+use crate::order_query_api::EnterPointSource;
+use crate::rate_limit::EndpointLimit;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[
+    EndpointLimit::uid("/api/v2/spot/trade/place-plan-order", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/modify-plan-order", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/cancel-plan-order", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/current-plan-order", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/history-plan-order", 20),
+    EndpointLimit::uid("/api/v2/spot/trade/plan-sub-order", 20),
+];
+
+/// 计划委托每页最大条数
+pub const PLAN_ORDERS_PAGE_SIZE: u32 = 100;
+
+/// 触发价格类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TriggerType {
+    /// 最新成交价
+    FillPrice,
+    /// 标记价格
+    MarkPrice,
+}
+
+/// 计划委托数量类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanType {
+    /// 按基础币数量
+    #[default]
+    Amount,
+    /// 按计价币金额
+    Total,
+}
+
+/// 计划委托状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PlanOrderStatus {
+    /// 未触发
+    Live,
+    /// 触发中
+    Executing,
+    /// 已触发
+    Executed,
+    /// 触发失败
+    FailExecute,
+    /// 已撤销
+    Cancelled,
+    /// 未知状态
+    #[serde(other)]
+    Unknown,
+}
+
+/// v2 现货计划委托下单参数
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotPlanOrderReq {
+    /// 交易对
+    pub symbol: String,
+    /// 买卖方向
+    pub side: Side,
+    /// 触发价格
+    pub trigger_price: String,
+    /// 触发后的委托类型
+    pub order_type: OrderType,
+    /// 触发后的委托价格（限价单必填）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_price: Option<String>,
+    /// 数量类型
+    pub plan_type: PlanType,
+    /// 数量：按 `plan_type` 为基础币数量或计价币金额
+    pub size: String,
+    /// 触发价格类型
+    pub trigger_type: TriggerType,
+    /// 自定义订单 ID（携带时下单请求允许自动重试）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid: Option<String>,
+    /// 订单有效方式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub force: Option<Force>,
+    /// 自成交保护模式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stp_mode: Option<StpMode>,
+}
+
+impl SpotPlanOrderReq {
+    /// 构造触发后以限价委托的计划委托
+    pub fn limit(
+        symbol: impl Into<String>,
+        side: Side,
+        trigger_price: impl Into<String>,
+        execute_price: impl Into<String>,
+        size: impl Into<String>,
+        trigger_type: TriggerType,
+    ) -> Self {
+        Self {
+            execute_price: Some(execute_price.into()),
+            ..Self::new(
+                symbol.into(),
+                side,
+                trigger_price.into(),
+                OrderType::Limit,
+                size.into(),
+                trigger_type,
+            )
+        }
+    }
+
+    /// 构造触发后以市价委托的计划委托
+    pub fn market(
+        symbol: impl Into<String>,
+        side: Side,
+        trigger_price: impl Into<String>,
+        size: impl Into<String>,
+        trigger_type: TriggerType,
+    ) -> Self {
+        Self::new(
+            symbol.into(),
+            side,
+            trigger_price.into(),
+            OrderType::Market,
+            size.into(),
+            trigger_type,
+        )
+    }
+
+    /// 设置数量类型
+    pub fn with_plan_type(mut self, plan_type: PlanType) -> Self {
+        self.plan_type = plan_type;
+        self
+    }
+
+    /// 设置自定义订单 ID
+    pub fn with_client_oid(mut self, client_oid: impl Into<String>) -> Self {
+        self.client_oid = Some(client_oid.into());
+        self
+    }
+
+    /// 构造请求，可选参数均为空
+    fn new(
+        symbol: String,
+        side: Side,
+        trigger_price: String,
+        order_type: OrderType,
+        size: String,
+        trigger_type: TriggerType,
+    ) -> Self {
+        Self {
+            symbol,
+            side,
+            trigger_price,
+            order_type,
+            execute_price: None,
+            plan_type: PlanType::default(),
+            size,
+            trigger_type,
+            client_oid: None,
+            force: None,
+            stp_mode: None,
+        }
+    }
+
+    /// 检查必填参数
+    fn validate(&self) -> Result<()> {
+        if self.side == Side::Unknown || self.order_type == OrderType::Unknown {
+            return Err(BitgetError::InvalidRequest(format!(
+                "计划委托的买卖方向与委托类型不能为 Unknown: {}",
+                self.symbol
+            )));
+        }
+        if self.order_type == OrderType::Limit && self.execute_price.is_none() {
+            return Err(BitgetError::InvalidRequest(format!(
+                "限价计划委托必须指定委托价格: {}",
+                self.symbol
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// v2 现货计划委托修改参数
+#[derive(Debug, Serialize, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ModifySpotPlanOrderReq {
+    /// 计划委托 ID（与 `client_oid` 二选一）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub order_id: Option<String>,
+    /// 计划委托自定义 ID
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid: Option<String>,
+    /// 新触发价格
+    pub trigger_price: String,
+    /// 触发后的委托类型
+    pub order_type: OrderType,
+    /// 触发后的委托价格（限价单必填）
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub execute_price: Option<String>,
+    /// 新数量
+    pub size: String,
+}
+
+impl ModifySpotPlanOrderReq {
+    /// 按计划委托 ID 修改
+    pub fn by_order_id(
+        order_id: impl Into<String>,
+        order_type: OrderType,
+        trigger_price: impl Into<String>,
+        size: impl Into<String>,
+    ) -> Self {
+        Self {
+            order_id: Some(order_id.into()),
+            client_oid: None,
+            trigger_price: trigger_price.into(),
+            order_type,
+            execute_price: None,
+            size: size.into(),
+        }
+    }
+
+    /// 按自定义订单 ID 修改
+    pub fn by_client_oid(
+        client_oid: impl Into<String>,
+        order_type: OrderType,
+        trigger_price: impl Into<String>,
+        size: impl Into<String>,
+    ) -> Self {
+        Self {
+            order_id: None,
+            client_oid: Some(client_oid.into()),
+            trigger_price: trigger_price.into(),
+            order_type,
+            execute_price: None,
+            size: size.into(),
+        }
+    }
+
+    /// 设置触发后的委托价格
+    pub fn with_execute_price(mut self, execute_price: impl Into<String>) -> Self {
+        self.execute_price = Some(execute_price.into());
+        self
+    }
+
+    /// 检查订单标识与必填参数
+    fn validate(&self) -> Result<()> {
+        if self.order_id.is_none() && self.client_oid.is_none() {
+            return Err(BitgetError::InvalidRequest(
+                "修改计划委托必须指定 orderId 或 clientOid".to_string(),
+            ));
+        }
+        if self.order_type == OrderType::Unknown {
+            return Err(BitgetError::InvalidRequest(
+                "计划委托的委托类型不能为 Unknown".to_string(),
+            ));
+        }
+        if self.order_type == OrderType::Limit && self.execute_price.is_none() {
+            return Err(BitgetError::InvalidRequest(
+                "限价计划委托必须指定委托价格".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// 计划委托下单 / 修改响应
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotPlanOrderResp {
+    pub order_id: Option<String>,
+    pub client_oid: Option<String>,
+}
+
+/// 计划委托撤单响应
+#[derive(Debug, Clone, Deserialize)]
+pub struct CancelPlanOrderResp {
+    /// 处理结果：success
+    pub result: Option<String>,
+}
+
+/// 计划委托查询参数（当前 / 历史计划委托）
+///
+/// 查询历史计划委托时 `symbol`、`start_time`、`end_time` 必填
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotPlanOrdersQuery {
+    /// 交易对
+    pub symbol: Option<String>,
+    /// 开始时间（毫秒时间戳）
+    pub start_time: Option<i64>,
+    /// 结束时间（毫秒时间戳）
+    pub end_time: Option<i64>,
+    /// 每页条数，最大 100
+    pub limit: Option<u32>,
+    /// 游标：返回 orderId 小于该值的记录
+    pub id_less_than: Option<String>,
+}
+
+impl SpotPlanOrdersQuery {
+    /// 按交易对构造查询参数
+    pub fn new(symbol: impl Into<String>) -> Self {
+        Self {
+            symbol: Some(symbol.into()),
+            ..Self::default()
+        }
+    }
+}
+
+/// 计划委托
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotPlanOrder {
+    pub order_id: Option<String>,
+    pub client_oid: Option<String>,
+    pub symbol: Option<String>,
+    pub side: Option<Side>,
+    pub trigger_price: Option<String>,
+    pub trigger_type: Option<TriggerType>,
+    pub order_type: Option<OrderType>,
+    pub execute_price: Option<String>,
+    pub plan_type: Option<PlanType>,
+    pub size: Option<String>,
+    pub status: Option<PlanOrderStatus>,
+    pub enter_point_source: Option<EnterPointSource>,
+    pub c_time: Option<String>,
+    pub u_time: Option<String>,
+}
+
+/// 计划委托分页结果
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SpotPlanOrderPage {
+    /// 是否还有下一页
+    #[serde(default)]
+    pub next_flag: bool,
+    /// 下一页游标，作为下次查询的 `id_less_than`
+    pub id_less_than: Option<String>,
+    /// 计划委托列表
+    #[serde(default)]
+    pub order_list: Vec<SpotPlanOrder>,
+}
+
+impl SpotPlanOrderPage {
+    /// 下一页的查询参数，已是最后一页或游标未前进时返回 None
+    fn next_query(&self, query: &SpotPlanOrdersQuery) -> Option<SpotPlanOrdersQuery> {
+        let cursor = self.id_less_than.as_ref().filter(|_| self.next_flag)?;
+        if self.order_list.is_empty() || query.id_less_than.as_ref() == Some(cursor) {
+            return None;
+        }
+        Some(SpotPlanOrdersQuery {
+            id_less_than: Some(cursor.clone()),
+            ..query.clone()
+        })
+    }
+}
+
+/// 全量翻页时的首页查询参数：每页条数按接口上限截断
+fn first_page_query(query: &SpotPlanOrdersQuery) -> SpotPlanOrdersQuery {
+    let limit = query
+        .limit
+        .filter(|&limit| limit > 0)
+        .map_or(PLAN_ORDERS_PAGE_SIZE, |limit| {
+            limit.min(PLAN_ORDERS_PAGE_SIZE)
+        });
+    SpotPlanOrdersQuery {
+        limit: Some(limit),
+        ..query.clone()
+    }
+}
+
+/// 计划委托触发后生成的子订单
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PlanSubOrderResp {
+    pub order_id: Option<String>,
+    pub price: Option<String>,
+    #[serde(rename = "type")]
+    pub order_type: Option<OrderType>,
+    pub status: Option<String>,
+}
+
+impl BitgetClient {
+    /// v2 现货计划委托下单
+    pub fn place_spot_plan_order(&self, req: &SpotPlanOrderReq) -> Result<SpotPlanOrderResp> {
+        req.validate()?;
+        self.request_typed(consts::POST, "/api/v2/spot/trade/place-plan-order", req)
+    }
+
+    /// v2 现货计划委托下单（异步版本）
+    pub async fn place_spot_plan_order_async(
+        &self,
+        req: &SpotPlanOrderReq,
+    ) -> Result<SpotPlanOrderResp> {
+        req.validate()?;
+        self.request_typed_async(consts::POST, "/api/v2/spot/trade/place-plan-order", req)
+            .await
+    }
+
+    /// v2 修改现货计划委托（仅未触发的委托可修改）
+    pub fn modify_spot_plan_order(
+        &self,
+        req: &ModifySpotPlanOrderReq,
+    ) -> Result<SpotPlanOrderResp> {
+        req.validate()?;
+        self.request_typed(consts::POST, "/api/v2/spot/trade/modify-plan-order", req)
+    }
+
+    /// v2 修改现货计划委托（异步版本）
+    pub async fn modify_spot_plan_order_async(
+        &self,
+        req: &ModifySpotPlanOrderReq,
+    ) -> Result<SpotPlanOrderResp> {
+        req.validate()?;
+        self.request_typed_async(consts::POST, "/api/v2/spot/trade/modify-plan-order", req)
+            .await
+    }
+
+    /// v2 按计划委托 ID 撤销现货计划委托
+    pub fn cancel_spot_plan_order(&self, order_id: &str) -> Result<CancelPlanOrderResp> {
+        info!("撤销计划委托 order_id={}", order_id);
+        let mut params = BTreeMap::new();
+        params.insert("orderId".to_string(), order_id.to_string());
+        self.request_typed(
+            consts::POST,
+            "/api/v2/spot/trade/cancel-plan-order",
+            &params,
+        )
+    }
+
+    /// v2 按计划委托 ID 撤销现货计划委托（异步版本）
+    pub async fn cancel_spot_plan_order_async(
+        &self,
+        order_id: &str,
+    ) -> Result<CancelPlanOrderResp> {
+        info!("撤销计划委托 order_id={}", order_id);
+        let mut params = BTreeMap::new();
+        params.insert("orderId".to_string(), order_id.to_string());
+        self.request_typed_async(
+            consts::POST,
+            "/api/v2/spot/trade/cancel-plan-order",
+            &params,
+        )
+        .await
+    }
+
+    /// v2 按自定义订单 ID 撤销现货计划委托
+    pub fn cancel_spot_plan_order_by_client_oid(
+        &self,
+        client_oid: &str,
+    ) -> Result<CancelPlanOrderResp> {
+        info!("撤销计划委托 client_oid={}", client_oid);
+        let mut params = BTreeMap::new();
+        params.insert("clientOid".to_string(), client_oid.to_string());
+        self.request_typed(
+            consts::POST,
+            "/api/v2/spot/trade/cancel-plan-order",
+            &params,
+        )
+    }
+
+    /// v2 按自定义订单 ID 撤销现货计划委托（异步版本）
+    pub async fn cancel_spot_plan_order_by_client_oid_async(
+        &self,
+        client_oid: &str,
+    ) -> Result<CancelPlanOrderResp> {
+        info!("撤销计划委托 client_oid={}", client_oid);
+        let mut params = BTreeMap::new();
+        params.insert("clientOid".to_string(), client_oid.to_string());
+        self.request_typed_async(
+            consts::POST,
+            "/api/v2/spot/trade/cancel-plan-order",
+            &params,
+        )
+        .await
+    }
+
+    /// v2 查询当前计划委托（单页，按返回的 `id_less_than` 翻页）
+    pub fn get_spot_current_plan_orders(
+        &self,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<SpotPlanOrderPage> {
+        info!("查询当前计划委托 symbol={:?}", query.symbol);
+        self.request_typed(consts::GET, "/api/v2/spot/trade/current-plan-order", query)
+    }
+
+    /// v2 查询当前计划委托（异步版本）
+    pub async fn get_spot_current_plan_orders_async(
+        &self,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<SpotPlanOrderPage> {
+        info!("查询当前计划委托 symbol={:?}", query.symbol);
+        self.request_typed_async(consts::GET, "/api/v2/spot/trade/current-plan-order", query)
+            .await
+    }
+
+    /// v2 查询历史计划委托（单页，按返回的 `id_less_than` 翻页）
+    pub fn get_spot_history_plan_orders(
+        &self,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<SpotPlanOrderPage> {
+        info!("查询历史计划委托 symbol={:?}", query.symbol);
+        self.request_typed(consts::GET, "/api/v2/spot/trade/history-plan-order", query)
+    }
+
+    /// v2 查询历史计划委托（异步版本）
+    pub async fn get_spot_history_plan_orders_async(
+        &self,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<SpotPlanOrderPage> {
+        info!("查询历史计划委托 symbol={:?}", query.symbol);
+        self.request_typed_async(consts::GET, "/api/v2/spot/trade/history-plan-order", query)
+            .await
+    }
+
+    /// v2 查询全部当前计划委托，按 `next_flag` / `id_less_than` 自动翻页
+    pub fn get_all_spot_current_plan_orders(
+        &self,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<Vec<SpotPlanOrder>> {
+        self.collect_plan_orders("/api/v2/spot/trade/current-plan-order", query)
+    }
+
+    /// v2 查询全部当前计划委托（异步版本）
+    pub async fn get_all_spot_current_plan_orders_async(
+        &self,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<Vec<SpotPlanOrder>> {
+        self.collect_plan_orders_async("/api/v2/spot/trade/current-plan-order", query)
+            .await
+    }
+
+    /// v2 查询全部历史计划委托，按 `next_flag` / `id_less_than` 自动翻页
+    pub fn get_all_spot_history_plan_orders(
+        &self,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<Vec<SpotPlanOrder>> {
+        self.collect_plan_orders("/api/v2/spot/trade/history-plan-order", query)
+    }
+
+    /// v2 查询全部历史计划委托（异步版本）
+    pub async fn get_all_spot_history_plan_orders_async(
+        &self,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<Vec<SpotPlanOrder>> {
+        self.collect_plan_orders_async("/api/v2/spot/trade/history-plan-order", query)
+            .await
+    }
+
+    /// 逐页查询计划委托直到最后一页
+    fn collect_plan_orders(
+        &self,
+        path: &str,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<Vec<SpotPlanOrder>> {
+        let mut orders = Vec::new();
+        let mut query = first_page_query(query);
+        loop {
+            let page: SpotPlanOrderPage = self.request_typed(consts::GET, path, &query)?;
+            let next = page.next_query(&query);
+            orders.extend(page.order_list);
+            match next {
+                Some(next) => query = next,
+                None => return Ok(orders),
+            }
+        }
+    }
+
+    /// 逐页查询计划委托直到最后一页（异步版本）
+    async fn collect_plan_orders_async(
+        &self,
+        path: &str,
+        query: &SpotPlanOrdersQuery,
+    ) -> Result<Vec<SpotPlanOrder>> {
+        let mut orders = Vec::new();
+        let mut query = first_page_query(query);
+        loop {
+            let page: SpotPlanOrderPage =
+                self.request_typed_async(consts::GET, path, &query).await?;
+            let next = page.next_query(&query);
+            orders.extend(page.order_list);
+            match next {
+                Some(next) => query = next,
+                None => return Ok(orders),
+            }
+        }
+    }
+
+    /// v2 查询计划委托触发后生成的子订单
+    pub fn get_spot_plan_sub_orders(&self, plan_order_id: &str) -> Result<Vec<PlanSubOrderResp>> {
+        info!("查询计划委托子订单 plan_order_id={}", plan_order_id);
+        let mut params = BTreeMap::new();
+        params.insert("planOrderId".to_string(), plan_order_id.to_string());
+        self.request_typed(consts::GET, "/api/v2/spot/trade/plan-sub-order", &params)
+    }
+
+    /// v2 查询计划委托触发后生成的子订单（异步版本）
+    pub async fn get_spot_plan_sub_orders_async(
+        &self,
+        plan_order_id: &str,
+    ) -> Result<Vec<PlanSubOrderResp>> {
+        info!("查询计划委托子订单 plan_order_id={}", plan_order_id);
+        let mut params = BTreeMap::new();
+        params.insert("planOrderId".to_string(), plan_order_id.to_string());
+        self.request_typed_async(consts::GET, "/api/v2/spot/trade/plan-sub-order", &params)
+            .await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn plan_order_body_uses_snake_case_enums() {
+        let req = SpotPlanOrderReq::limit(
+            "BTCUSDT",
+            Side::Buy,
+            "29000",
+            "28900",
+            "0.01",
+            TriggerType::FillPrice,
+        )
+        .with_client_oid("plan-1");
+        assert!(req.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            json!({
+                "symbol": "BTCUSDT",
+                "side": "buy",
+                "triggerPrice": "29000",
+                "orderType": "limit",
+                "executePrice": "28900",
+                "planType": "amount",
+                "size": "0.01",
+                "triggerType": "fill_price",
+                "clientOid": "plan-1"
+            })
+        );
+
+        let req = SpotPlanOrderReq::market(
+            "BTCUSDT",
+            Side::Sell,
+            "31000",
+            "100",
+            TriggerType::MarkPrice,
+        )
+        .with_plan_type(PlanType::Total);
+        let body = serde_json::to_value(&req).unwrap();
+        assert_eq!(body["triggerType"], json!("mark_price"));
+        assert_eq!(body["planType"], json!("total"));
+        assert_eq!(body["orderType"], json!("market"));
+        assert!(body.get("executePrice").is_none());
+    }
+
+    #[test]
+    fn plan_order_validation() {
+        let mut req = SpotPlanOrderReq::limit(
+            "BTCUSDT",
+            Side::Buy,
+            "29000",
+            "28900",
+            "0.01",
+            TriggerType::FillPrice,
+        );
+        req.execute_price = None;
+        assert!(matches!(
+            req.validate(),
+            Err(BitgetError::InvalidRequest(_))
+        ));
+
+        let mut req =
+            SpotPlanOrderReq::market("BTCUSDT", Side::Buy, "29000", "10", TriggerType::FillPrice);
+        req.side = Side::Unknown;
+        assert!(matches!(
+            req.validate(),
+            Err(BitgetError::InvalidRequest(_))
+        ));
+    }
+
+    #[test]
+    fn modify_plan_order_body() {
+        let req = ModifySpotPlanOrderReq::by_order_id("1", OrderType::Limit, "29500", "0.02")
+            .with_execute_price("29400");
+        assert!(req.validate().is_ok());
+        assert_eq!(
+            serde_json::to_value(&req).unwrap(),
+            json!({
+                "orderId": "1",
+                "triggerPrice": "29500",
+                "orderType": "limit",
+                "executePrice": "29400",
+                "size": "0.02"
+            })
+        );
+
+        let req = ModifySpotPlanOrderReq::by_client_oid("plan-1", OrderType::Market, "29500", "10");
+        let body = serde_json::to_value(&req).unwrap();
+        assert_eq!(body["clientOid"], json!("plan-1"));
+        assert!(body.get("orderId").is_none());
+    }
+
+    #[test]
+    fn modify_plan_order_validation() {
+        let limit_without_price =
+            ModifySpotPlanOrderReq::by_order_id("1", OrderType::Limit, "29500", "0.02");
+        assert!(matches!(
+            limit_without_price.validate(),
+            Err(BitgetError::InvalidRequest(_))
+        ));
+
+        let mut no_id = ModifySpotPlanOrderReq::by_order_id("1", OrderType::Market, "29500", "10");
+        no_id.order_id = None;
+        assert!(no_id.validate().is_err());
+
+        let unknown = ModifySpotPlanOrderReq::by_order_id("1", OrderType::Unknown, "29500", "10");
+        assert!(unknown.validate().is_err());
+    }
+
+    #[test]
+    fn plan_order_parses_enter_point_source() {
+        let order: SpotPlanOrder = serde_json::from_value(json!({
+            "orderId": "1",
+            "side": "buy",
+            "triggerType": "fill_price",
+            "planType": "amount",
+            "status": "live",
+            "enterPointSource": "API"
+        }))
+        .unwrap();
+        assert_eq!(order.enter_point_source, Some(EnterPointSource::Api));
+        assert_eq!(order.status, Some(PlanOrderStatus::Live));
+
+        let order: SpotPlanOrder =
+            serde_json::from_value(json!({"orderId": "1", "enterPointSource": "BOT"})).unwrap();
+        assert_eq!(order.enter_point_source, Some(EnterPointSource::Unknown));
+    }
+
+    fn page(next_flag: bool, id_less_than: Option<&str>, orders: usize) -> SpotPlanOrderPage {
+        serde_json::from_value(json!({
+            "nextFlag": next_flag,
+            "idLessThan": id_less_than,
+            "orderList": vec![json!({"orderId": "1"}); orders],
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn first_page_limit_is_clamped() {
+        let query = SpotPlanOrdersQuery::new("BTCUSDT");
+        assert_eq!(first_page_query(&query).limit, Some(PLAN_ORDERS_PAGE_SIZE));
+
+        let query = SpotPlanOrdersQuery {
+            limit: Some(500),
+            ..SpotPlanOrdersQuery::new("BTCUSDT")
+        };
+        assert_eq!(first_page_query(&query).limit, Some(PLAN_ORDERS_PAGE_SIZE));
+
+        let query = SpotPlanOrdersQuery {
+            limit: Some(20),
+            ..SpotPlanOrdersQuery::new("BTCUSDT")
+        };
+        assert_eq!(first_page_query(&query).limit, Some(20));
+    }
+
+    #[test]
+    fn next_query_follows_cursor() {
+        let query = first_page_query(&SpotPlanOrdersQuery::new("BTCUSDT"));
+        let next = page(true, Some("123"), 2).next_query(&query).unwrap();
+        assert_eq!(next.id_less_than.as_deref(), Some("123"));
+        assert_eq!(next.symbol.as_deref(), Some("BTCUSDT"));
+        assert_eq!(next.limit, query.limit);
+    }
+
+    #[test]
+    fn next_query_stops_on_last_page() {
+        let query = SpotPlanOrdersQuery::new("BTCUSDT");
+        assert!(page(false, Some("123"), 2).next_query(&query).is_none());
+        assert!(page(true, None, 2).next_query(&query).is_none());
+        assert!(page(true, Some("123"), 0).next_query(&query).is_none());
+
+        let query = SpotPlanOrdersQuery {
+            id_less_than: Some("123".to_string()),
+            ..query
+        };
+        assert!(page(true, Some("123"), 2).next_query(&query).is_none());
+    }
+}
@@ -0,0 +1,402 @@
+//! Bitget 接口限频
+//!
+//! 基于令牌桶实现，按（账户, 接口路径）分别计数。各接口模块通过 `RATE_LIMITS`
+//! 声明 Bitget 官方公布的频率限制，本模块负责汇总并在发送请求前申请令牌
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use crate::exceptions::{BitgetError, Result};
+use crate::{
+    account_api, asset_api, fund_flow_api, history_api, market_api, order_api, order_query_api,
+    plan_order_api, time_sync, transfer_api,
+};
+
+/// 限频维度
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LimitScope {
+    /// 按账户（UID）限频
+    Uid,
+    /// 按 IP 限频（公共接口），同一进程内所有账户共享
+    Ip,
+}
+
+/// 单个接口的频率限制
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct EndpointLimit {
+    /// 接口路径
+    pub path: &'static str,
+    /// 每秒请求数
+    pub per_second: u32,
+    /// 限频维度
+    pub scope: LimitScope,
+}
+
+impl EndpointLimit {
+    /// 按账户限频的接口
+    pub const fn uid(path: &'static str, per_second: u32) -> Self {
+        Self {
+            path,
+            per_second,
+            scope: LimitScope::Uid,
+        }
+    }
+
+    /// 按 IP 限频的接口
+    pub const fn ip(path: &'static str, per_second: u32) -> Self {
+        Self {
+            path,
+            per_second,
+            scope: LimitScope::Ip,
+        }
+    }
+}
+
+/// 令牌不足时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum RateLimitMode {
+    /// 等待令牌恢复后继续发送
+    #[default]
+    Wait,
+    /// 立即返回 `BitgetError::LocalRateLimited`，不参与重试
+    FailFast,
+}
+
+/// 所有接口模块声明的频率限制
+pub fn builtin_limits() -> impl Iterator<Item = &'static EndpointLimit> {
+    [
+        account_api::RATE_LIMITS,
+        asset_api::RATE_LIMITS,
+        fund_flow_api::RATE_LIMITS,
+        history_api::RATE_LIMITS,
+        market_api::RATE_LIMITS,
+        order_api::RATE_LIMITS,
+        order_query_api::RATE_LIMITS,
+        plan_order_api::RATE_LIMITS,
+        time_sync::RATE_LIMITS,
+        transfer_api::RATE_LIMITS,
+    ]
+    .into_iter()
+    .flatten()
+}
+
+/// 令牌桶
+#[derive(Debug)]
+struct TokenBucket {
+    /// 桶容量（即每秒请求数）
+    capacity: f64,
+    /// 当前令牌数
+    tokens: f64,
+    /// 上次补充令牌的时刻
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    fn new(per_second: u32) -> Self {
+        let capacity = f64::from(per_second.max(1));
+        Self {
+            capacity,
+            tokens: capacity,
+            last_refill: Instant::now(),
+        }
+    }
+
+    /// 尝试取出一个令牌，不足时返回需要等待的时间
+    fn try_take(&mut self) -> std::result::Result<(), Duration> {
+        let now = Instant::now();
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.capacity).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.capacity))
+        }
+    }
+}
+
+/// 令牌桶限频器
+///
+/// 可在多个客户端之间共享（`Arc<RateLimiter>`），按（账户, 接口路径）分别限频
+pub struct RateLimiter {
+    /// 令牌不足时的处理方式
+    mode: RateLimitMode,
+    /// 接口路径 -> 频率限制
+    limits: HashMap<String, EndpointLimit>,
+    /// 未声明接口的默认限制（每秒请求数），None 表示不限
+    default_per_second: Option<u32>,
+    /// （账户, 接口路径）-> 令牌桶
+    buckets: Mutex<HashMap<(String, String), TokenBucket>>,
+}
+
+impl fmt::Debug for RateLimiter {
+    // 令牌桶以 API Key 为键，Debug 输出只保留数量
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let buckets = self.buckets.lock().map(|b| b.len()).unwrap_or_default();
+        f.debug_struct("RateLimiter")
+            .field("mode", &self.mode)
+            .field("limits", &self.limits.len())
+            .field("default_per_second", &self.default_per_second)
+            .field("buckets", &buckets)
+            .finish()
+    }
+}
+
+impl Default for RateLimiter {
+    fn default() -> Self {
+        Self::new(RateLimitMode::default())
+    }
+}
+
+impl RateLimiter {
+    /// 使用内置频率限制表创建限频器
+    ///
+    /// # 参数
+    /// * `mode` - 令牌不足时的处理方式
+    pub fn new(mode: RateLimitMode) -> Self {
+        Self {
+            mode,
+            limits: builtin_limits()
+                .map(|limit| (limit.path.to_string(), *limit))
+                .collect(),
+            default_per_second: None,
+            buckets: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// 覆盖或新增某个接口的频率限制
+    pub fn with_limit(mut self, limit: EndpointLimit) -> Self {
+        self.limits.insert(limit.path.to_string(), limit);
+        self
+    }
+
+    /// 为未声明的接口设置默认频率限制（按账户）
+    pub fn with_default_limit(mut self, per_second: u32) -> Self {
+        self.default_per_second = Some(per_second);
+        self
+    }
+
+    /// 令牌不足时的处理方式
+    pub fn mode(&self) -> RateLimitMode {
+        self.mode
+    }
+
+    /// 查询接口对应的频率限制
+    pub fn limit_for(&self, path: &str) -> Option<EndpointLimit> {
+        self.limits.get(path).copied().or_else(|| {
+            self.default_per_second.map(|per_second| EndpointLimit {
+                path: "",
+                per_second,
+                scope: LimitScope::Uid,
+            })
+        })
+    }
+
+    /// 尝试申请一个令牌（不等待）
+    ///
+    /// # 参数
+    /// * `account` - 账户标识（API Key 或 UID）
+    /// * `path` - 接口路径
+    ///
+    /// # 返回
+    /// 成功返回 Ok，令牌不足时返回需要等待的时间
+    pub fn try_acquire(&self, account: &str, path: &str) -> std::result::Result<(), Duration> {
+        let Some(limit) = self.limit_for(path) else {
+            return Ok(());
+        };
+        let account = match limit.scope {
+            LimitScope::Uid => account.to_string(),
+            LimitScope::Ip => String::new(),
+        };
+
+        let mut buckets = self.buckets.lock().unwrap_or_else(|e| e.into_inner());
+        buckets
+            .entry((account, path.to_string()))
+            .or_insert_with(|| TokenBucket::new(limit.per_second))
+            .try_take()
+    }
+
+    /// 申请一个令牌（同步），`Wait` 模式下阻塞当前线程直到令牌可用
+    ///
+    /// # 参数
+    /// * `account` - 账户标识（API Key 或 UID）
+    /// * `path` - 接口路径
+    ///
+    /// # 返回
+    /// 成功返回 Ok，`FailFast` 模式下令牌不足返回 `BitgetError::LocalRateLimited`
+    pub fn acquire_blocking(&self, account: &str, path: &str) -> Result<()> {
+        while let Err(wait) = self.try_acquire(account, path) {
+            std::thread::sleep(self.on_exhausted(path, wait)?);
+        }
+        Ok(())
+    }
+
+    /// 申请一个令牌（异步），`Wait` 模式下等待令牌可用
+    ///
+    /// # 参数
+    /// * `account` - 账户标识（API Key 或 UID）
+    /// * `path` - 接口路径
+    ///
+    /// # 返回
+    /// 成功返回 Ok，`FailFast` 模式下令牌不足返回 `BitgetError::LocalRateLimited`
+    pub async fn acquire(&self, account: &str, path: &str) -> Result<()> {
+        while let Err(wait) = self.try_acquire(account, path) {
+            tokio::time::sleep(self.on_exhausted(path, wait)?).await;
+        }
+        Ok(())
+    }
+
+    /// 令牌不足时按模式处理：`FailFast` 返回错误，`Wait` 返回需要等待的时间
+    fn on_exhausted(&self, path: &str, wait: Duration) -> Result<Duration> {
+        match self.mode {
+            RateLimitMode::FailFast => Err(BitgetError::LocalRateLimited {
+                path: path.to_string(),
+                retry_after: wait,
+            }),
+            RateLimitMode::Wait => Ok(wait),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bucket_reports_wait_until_next_token() {
+        let mut bucket = TokenBucket::new(4);
+        for _ in 0..4 {
+            assert!(bucket.try_take().is_ok());
+        }
+        let wait = bucket.try_take().unwrap_err();
+        assert!(wait > Duration::from_millis(200) && wait <= Duration::from_millis(250));
+
+        // 半个令牌已恢复时只需再等剩下的一半
+        bucket.tokens = 0.5;
+        bucket.last_refill = Instant::now();
+        let wait = bucket.try_take().unwrap_err();
+        assert!(wait > Duration::from_millis(100) && wait <= Duration::from_millis(125));
+    }
+
+    #[test]
+    fn bucket_refills_with_elapsed_time() {
+        let mut bucket = TokenBucket::new(2);
+        bucket.tokens = 0.0;
+        bucket.last_refill = Instant::now() - Duration::from_millis(600);
+        assert!(bucket.try_take().is_ok());
+        assert!(bucket.try_take().is_err());
+
+        // 空闲再久也不超过桶容量
+        bucket.tokens = 0.0;
+        bucket.last_refill = Instant::now() - Duration::from_secs(60);
+        assert!(bucket.try_take().is_ok());
+        assert!(bucket.try_take().is_ok());
+        assert!(bucket.try_take().is_err());
+    }
+
+    #[test]
+    fn uid_scope_is_per_account() {
+        let limiter =
+            RateLimiter::new(RateLimitMode::Wait).with_limit(EndpointLimit::uid("/uid", 1));
+        assert!(limiter.try_acquire("alice", "/uid").is_ok());
+        assert!(limiter.try_acquire("alice", "/uid").is_err());
+        assert!(limiter.try_acquire("bob", "/uid").is_ok());
+    }
+
+    #[test]
+    fn ip_scope_is_shared_across_accounts() {
+        let limiter = RateLimiter::new(RateLimitMode::Wait).with_limit(EndpointLimit::ip("/ip", 1));
+        assert!(limiter.try_acquire("alice", "/ip").is_ok());
+        assert!(limiter.try_acquire("bob", "/ip").is_err());
+    }
+
+    #[test]
+    fn paths_have_separate_buckets() {
+        let limiter = RateLimiter::new(RateLimitMode::Wait)
+            .with_limit(EndpointLimit::uid("/a", 1))
+            .with_limit(EndpointLimit::uid("/b", 1));
+        assert!(limiter.try_acquire("alice", "/a").is_ok());
+        assert!(limiter.try_acquire("alice", "/b").is_ok());
+        assert!(limiter.try_acquire("alice", "/a").is_err());
+    }
+
+    #[test]
+    fn with_limit_overrides_builtin_table() {
+        let path = "/api/v2/spot/trade/place-order";
+        assert_eq!(
+            RateLimiter::default().limit_for(path),
+            Some(EndpointLimit::uid(path, 10))
+        );
+
+        let limiter = RateLimiter::default().with_limit(EndpointLimit::ip(path, 1));
+        assert_eq!(limiter.limit_for(path), Some(EndpointLimit::ip(path, 1)));
+        assert!(limiter.try_acquire("alice", path).is_ok());
+        assert!(limiter.try_acquire("bob", path).is_err());
+    }
+
+    #[test]
+    fn undeclared_paths_use_default_limit() {
+        let limiter = RateLimiter::default();
+        assert_eq!(limiter.limit_for("/unknown"), None);
+        for _ in 0..100 {
+            assert!(limiter.try_acquire("alice", "/unknown").is_ok());
+        }
+
+        let limiter = RateLimiter::default().with_default_limit(1);
+        let limit = limiter.limit_for("/unknown").unwrap();
+        assert_eq!((limit.per_second, limit.scope), (1, LimitScope::Uid));
+        assert!(limiter.try_acquire("alice", "/unknown").is_ok());
+        assert!(limiter.try_acquire("alice", "/unknown").is_err());
+        assert!(limiter.try_acquire("bob", "/unknown").is_ok());
+    }
+
+    #[test]
+    fn fail_fast_returns_local_rate_limited() {
+        let limiter =
+            RateLimiter::new(RateLimitMode::FailFast).with_limit(EndpointLimit::uid("/ff", 1));
+        assert!(limiter.acquire_blocking("alice", "/ff").is_ok());
+        match limiter.acquire_blocking("alice", "/ff") {
+            Err(BitgetError::LocalRateLimited { path, retry_after }) => {
+                assert_eq!(path, "/ff");
+                assert!(retry_after <= Duration::from_secs(1));
+            }
+            other => panic!("expected LocalRateLimited, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn wait_mode_blocks_until_refilled() {
+        let limiter =
+            RateLimiter::new(RateLimitMode::Wait).with_limit(EndpointLimit::uid("/wait", 20));
+        for _ in 0..20 {
+            limiter.acquire_blocking("alice", "/wait").unwrap();
+        }
+        let start = Instant::now();
+        limiter.acquire_blocking("alice", "/wait").unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+
+    #[tokio::test]
+    async fn async_acquire_follows_mode() {
+        let limiter =
+            RateLimiter::new(RateLimitMode::FailFast).with_limit(EndpointLimit::uid("/a", 1));
+        limiter.acquire("alice", "/a").await.unwrap();
+        assert!(matches!(
+            limiter.acquire("alice", "/a").await,
+            Err(BitgetError::LocalRateLimited { .. })
+        ));
+
+        let limiter =
+            RateLimiter::new(RateLimitMode::Wait).with_limit(EndpointLimit::uid("/a", 20));
+        for _ in 0..20 {
+            limiter.acquire("alice", "/a").await.unwrap();
+        }
+        let start = Instant::now();
+        limiter.acquire("alice", "/a").await.unwrap();
+        assert!(start.elapsed() >= Duration::from_millis(40));
+    }
+}
@@ -0,0 +1,232 @@
+//! 多账户客户端注册表
+//!
+//! `AccountRegistry` 按别名（以及可选的 UID）管理主账户与子账户的 `BitgetClient`。
+//! 所有账户的客户端由同一个基础客户端派生，共享 HTTP 连接池、限频器与时钟偏差；
+//! 限频器按 UID 维护令牌桶，因此每个账户独立计算配额
+
+use futures_util::future::join_all;
+use std::collections::{BTreeMap, HashMap};
+use std::future::Future;
+use std::sync::Arc;
+
+use crate::asset_api::AssetInfoResp;
+use crate::client::BitgetClient;
+use crate::credentials::Credentials;
+use crate::exceptions::{BitgetError, Result};
+use crate::signer::Signer;
+
+/// 多账户客户端注册表
+#[derive(Debug, Clone)]
+pub struct AccountRegistry {
+    /// 基础客户端，提供共享的 HTTP 连接池、限频器与其他配置
+    base: BitgetClient,
+    /// 别名 -> 客户端
+    accounts: BTreeMap<String, BitgetClient>,
+    /// UID -> 别名
+    uids: HashMap<String, String>,
+}
+
+impl AccountRegistry {
+    /// 创建注册表
+    ///
+    /// # 参数
+    /// * `base` - 基础客户端（通常由 `BitgetClient::builder().public_only()` 构造，不需要凭证），
+    ///   所有账户共享其连接池、限频器、重试策略与中间件
+    pub fn new(base: BitgetClient) -> Self {
+        Self {
+            base,
+            accounts: BTreeMap::new(),
+            uids: HashMap::new(),
+        }
+    }
+
+    /// 注册账户（HMAC 签名）
+    ///
+    /// # 参数
+    /// * `alias` - 账户别名
+    /// * `uid` - 账户 UID，设置后可按 UID 查找，并按 UID 限频
+    /// * `credentials` - API 凭证
+    ///
+    /// # 返回
+    /// 返回注册后的客户端，别名或 UID 重复时返回错误
+    pub fn add(
+        &mut self,
+        alias: impl Into<String>,
+        uid: Option<&str>,
+        credentials: &Credentials,
+    ) -> Result<&BitgetClient> {
+        let client = self
+            .base
+            .with_credentials(credentials, uid.map(str::to_string));
+        self.insert(alias.into(), client)
+    }
+
+    /// 注册账户（自定义签名器）
+    ///
+    /// # 参数
+    /// * `alias` - 账户别名
+    /// * `uid` - 账户 UID，设置后可按 UID 查找，并按 UID 限频
+    /// * `api_key` - API 密钥
+    /// * `signer` - 请求签名器
+    /// * `passphrase` - API 密码短语
+    ///
+    /// # 返回
+    /// 返回注册后的客户端，别名或 UID 重复时返回错误
+    pub fn add_with_signer(
+        &mut self,
+        alias: impl Into<String>,
+        uid: Option<&str>,
+        api_key: &str,
+        signer: Arc<dyn Signer>,
+        passphrase: &str,
+    ) -> Result<&BitgetClient> {
+        let client = self
+            .base
+            .with_account(api_key, signer, passphrase, uid.map(str::to_string));
+        self.insert(alias.into(), client)
+    }
+
+    /// 移除账户
+    pub fn remove(&mut self, alias: &str) -> Option<BitgetClient> {
+        let client = self.accounts.remove(alias)?;
+        if let Some(uid) = client.uid() {
+            self.uids.remove(uid);
+        }
+        Some(client)
+    }
+
+    /// 按别名获取客户端
+    pub fn get(&self, alias: &str) -> Option<&BitgetClient> {
+        self.accounts.get(alias)
+    }
+
+    /// 按 UID 获取客户端
+    pub fn by_uid(&self, uid: &str) -> Option<&BitgetClient> {
+        self.uids
+            .get(uid)
+            .and_then(|alias| self.accounts.get(alias))
+    }
+
+    /// 全部账户别名（按字典序）
+    pub fn aliases(&self) -> impl Iterator<Item = &str> {
+        self.accounts.keys().map(String::as_str)
+    }
+
+    /// 遍历全部账户
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &BitgetClient)> {
+        self.accounts
+            .iter()
+            .map(|(alias, client)| (alias.as_str(), client))
+    }
+
+    /// 账户数量
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    /// 是否没有账户
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    /// 基础客户端
+    pub fn base(&self) -> &BitgetClient {
+        &self.base
+    }
+
+    /// 对全部账户并发执行同步操作（每个账户一个线程）
+    ///
+    /// # 参数
+    /// * `f` - 对单个账户执行的操作
+    ///
+    /// # 返回
+    /// 返回别名到各账户执行结果的映射，单个账户失败不影响其他账户
+    pub fn for_each<T, F>(&self, f: F) -> BTreeMap<String, Result<T>>
+    where
+        T: Send,
+        F: Fn(&BitgetClient) -> Result<T> + Sync,
+    {
+        let f = &f;
+        std::thread::scope(|scope| {
+            let handles: Vec<_> = self
+                .accounts
+                .iter()
+                .map(|(alias, client)| (alias.clone(), scope.spawn(move || f(client))))
+                .collect();
+            handles
+                .into_iter()
+                .map(|(alias, handle)| {
+                    let result = handle.join().unwrap_or_else(|_| {
+                        Err(BitgetError::InvalidRequest(format!(
+                            "账户 {} 的操作发生 panic",
+                            alias
+                        )))
+                    });
+                    (alias, result)
+                })
+                .collect()
+        })
+    }
+
+    /// 对全部账户并发执行异步操作
+    ///
+    /// # 参数
+    /// * `f` - 对单个账户执行的操作（接收客户端的克隆）
+    ///
+    /// # 返回
+    /// 返回别名到各账户执行结果的映射，单个账户失败不影响其他账户
+    pub async fn for_each_async<T, F, Fut>(&self, f: F) -> BTreeMap<String, Result<T>>
+    where
+        F: Fn(BitgetClient) -> Fut,
+        Fut: Future<Output = Result<T>>,
+    {
+        let results = join_all(self.accounts.values().map(|client| f(client.clone()))).await;
+        self.accounts.keys().cloned().zip(results).collect()
+    }
+
+    /// 查询全部账户的指定币种资产
+    ///
+    /// # 参数
+    /// * `coin` - 币种
+    ///
+    /// # 返回
+    /// 返回别名到各账户资产的映射
+    pub fn get_all_assets(&self, coin: &str) -> BTreeMap<String, Result<Vec<AssetInfoResp>>> {
+        self.for_each(|client| client.get_asset_v2(coin))
+    }
+
+    /// 查询全部账户的指定币种资产（异步版本）
+    ///
+    /// # 参数
+    /// * `coin` - 币种
+    ///
+    /// # 返回
+    /// 返回别名到各账户资产的映射
+    pub async fn get_all_assets_async(
+        &self,
+        coin: &str,
+    ) -> BTreeMap<String, Result<Vec<AssetInfoResp>>> {
+        self.for_each_async(|client| async move { client.get_asset_v2_async(coin).await })
+            .await
+    }
+
+    /// 检查别名与 UID 未被占用后加入注册表
+    fn insert(&mut self, alias: String, client: BitgetClient) -> Result<&BitgetClient> {
+        if self.accounts.contains_key(&alias) {
+            return Err(BitgetError::InvalidRequest(format!(
+                "账户别名已存在: {}",
+                alias
+            )));
+        }
+        if let Some(uid) = client.uid() {
+            if self.uids.contains_key(uid) {
+                return Err(BitgetError::InvalidRequest(format!(
+                    "账户 UID 已存在: {}",
+                    uid
+                )));
+            }
+            self.uids.insert(uid.to_string(), alias.clone());
+        }
+        Ok(self.accounts.entry(alias).or_insert(client))
+    }
+}
@@ -0,0 +1,114 @@
+//! Bitget 统一响应结构
+//!
+//! Bitget REST 接口统一返回 `{code, msg, requestTime, data}` 结构，
+//! 该模块提供对应的泛型封装，供各接口模块返回强类型的 `data`，
+//! 以及从响应头解析出的元数据（状态码、请求 ID、剩余配额、服务器时间）
+
+use reqwest::header::HeaderMap;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+
+use crate::consts;
+use crate::exceptions::{self, BitgetError, Result};
+
+/// Bitget 成功响应码
+pub const SUCCESS_CODE: &str = "00000";
+
+/// Bitget API 统一响应信封
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ApiResponse<T> {
+    /// 响应码，成功为 "00000"
+    pub code: String,
+
+    /// 响应消息
+    pub msg: Option<String>,
+
+    /// 服务器处理请求的时间（毫秒时间戳）
+    pub request_time: Option<u64>,
+
+    /// 业务数据
+    pub data: T,
+}
+
+impl<T: DeserializeOwned> ApiResponse<T> {
+    /// 解析响应文本
+    ///
+    /// 响应码不为 "00000" 时通过 `exceptions::parse_error_response` 转换为 `BitgetError::Api`
+    ///
+    /// # 参数
+    /// * `text` - 响应文本
+    ///
+    /// # 返回
+    /// 返回解析后的响应信封或错误
+    pub fn from_text(text: &str) -> Result<Self> {
+        if let Some(err) = exceptions::parse_error_response(text) {
+            return Err(err.into());
+        }
+        serde_json::from_str(text).map_err(|e| BitgetError::decode(e, text))
+    }
+}
+
+/// 原始 HTTP 响应
+#[derive(Debug, Clone)]
+pub struct RawResponse {
+    /// HTTP 状态码
+    pub status: u16,
+
+    /// 响应头
+    pub headers: HeaderMap,
+
+    /// 响应文本
+    pub body: String,
+}
+
+impl RawResponse {
+    /// 从响应头解析响应元数据
+    pub fn meta(&self) -> ResponseMeta {
+        let header = |name: &str| {
+            self.headers
+                .get(name)
+                .and_then(|v| v.to_str().ok())
+                .map(|v| v.trim().to_string())
+        };
+
+        ResponseMeta {
+            status: self.status,
+            request_id: header(consts::REQUEST_ID_HEADER),
+            remaining_limit: header(consts::USED_REMAIN_LIMIT_HEADER).and_then(|v| v.parse().ok()),
+            server_time: header(reqwest::header::DATE.as_str())
+                .and_then(|v| chrono::DateTime::parse_from_rfc2822(&v).ok())
+                .map(|t| t.timestamp_millis()),
+            request_time: None,
+        }
+    }
+}
+
+/// 响应元数据
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ResponseMeta {
+    /// HTTP 状态码
+    pub status: u16,
+
+    /// 请求 ID（`x-request-id` 响应头）
+    pub request_id: Option<String>,
+
+    /// 当前限频窗口剩余请求数（`x-mbx-used-remain-limit` 响应头）
+    pub remaining_limit: Option<u32>,
+
+    /// 服务器时间（`Date` 响应头，毫秒时间戳，精度为秒）
+    pub server_time: Option<i64>,
+
+    /// 服务器处理请求的时间（响应体 `requestTime`，毫秒时间戳）
+    pub request_time: Option<u64>,
+}
+
+/// 带响应元数据的业务数据
+#[derive(Debug, Clone)]
+pub struct MetaResponse<T> {
+    /// 业务数据
+    pub data: T,
+
+    /// 响应元数据
+    pub meta: ResponseMeta,
+}
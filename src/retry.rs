@@ -0,0 +1,271 @@
+//! 请求重试策略
+//!
+//! 支持最大重试次数、指数退避与随机抖动，并按错误类别（超时、5xx、429、可重试的业务错误码）决定是否重试。
+//! 下单、划转等非幂等接口默认只在携带 clientOid 时重试，避免重复下单
+
+use rand::Rng;
+use serde::Serialize;
+use serde_json::Value;
+use std::time::Duration;
+
+use crate::consts;
+use crate::exceptions::BitgetError;
+
+/// 非幂等接口：仅当请求携带 clientOid 时才允许重试
+pub const NON_IDEMPOTENT_PATHS: &[&str] = &[
+    "/api/v2/spot/trade/place-order",
+    "/api/v2/spot/trade/batch-orders",
+    "/api/v2/spot/trade/cancel-replace-order",
+    "/api/v2/spot/trade/batch-cancel-replace-order",
+    "/api/v2/spot/trade/place-plan-order",
+    "/api/spot/v1/wallet/transfer",
+];
+
+/// 需要重试的错误类别
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryOn {
+    /// 连接失败、超时
+    pub timeouts: bool,
+    /// HTTP 5xx
+    pub server_errors: bool,
+    /// HTTP 429 / 限频错误码
+    pub rate_limited: bool,
+    /// 可重试的 Bitget 业务错误码（系统繁忙、时间戳过期等）
+    pub api_errors: bool,
+}
+
+impl Default for RetryOn {
+    fn default() -> Self {
+        Self {
+            timeouts: true,
+            server_errors: true,
+            rate_limited: true,
+            api_errors: true,
+        }
+    }
+}
+
+/// 重试策略
+#[derive(Debug, Clone, PartialEq)]
+pub struct RetryPolicy {
+    /// 最大尝试次数（含首次请求），1 表示不重试
+    pub max_attempts: u32,
+    /// 首次重试前的基础等待时间
+    pub base_delay: Duration,
+    /// 单次等待时间上限
+    pub max_delay: Duration,
+    /// 是否加入随机抖动（在 [delay/2, delay] 区间内随机）
+    pub jitter: bool,
+    /// 需要重试的错误类别
+    pub retry_on: RetryOn,
+    /// 是否允许重试未携带 clientOid 的非幂等请求
+    pub retry_non_idempotent: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(200),
+            max_delay: Duration::from_secs(5),
+            jitter: true,
+            retry_on: RetryOn::default(),
+            retry_non_idempotent: false,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// 不重试
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// 错误是否属于需要重试的类别
+    ///
+    /// 只重试服务端返回的限频（HTTP 429 / 限频错误码）；本地限频器在 `FailFast` 模式下
+    /// 返回的 `LocalRateLimited` 直接交给调用方
+    pub fn should_retry(&self, err: &BitgetError) -> bool {
+        match err {
+            BitgetError::Transport(e) => {
+                self.retry_on.timeouts && (e.is_timeout() || e.is_connect())
+            }
+            BitgetError::Http { status, .. } => self.retry_on.server_errors && *status >= 500,
+            BitgetError::RateLimited { .. } => self.retry_on.rate_limited,
+            BitgetError::Api(e) => {
+                let kind = e.kind();
+                if kind == crate::exceptions::BitgetErrorKind::RateLimited {
+                    self.retry_on.rate_limited
+                } else {
+                    self.retry_on.api_errors && kind.is_retryable()
+                }
+            }
+            _ => false,
+        }
+    }
+
+    /// 第 `attempt` 次失败后的等待时间（attempt 从 1 开始）
+    ///
+    /// 指数退避：base_delay * 2^(attempt-1)，不超过 max_delay；限频错误携带的 Retry-After 优先
+    pub fn delay(&self, attempt: u32, err: &BitgetError) -> Duration {
+        let exp = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(attempt.saturating_sub(1)))
+            .min(self.max_delay);
+        let delay = if self.jitter && !exp.is_zero() {
+            let half = exp / 2;
+            half + rand::thread_rng().gen_range(Duration::ZERO..=exp - half)
+        } else {
+            exp
+        };
+
+        match err {
+            BitgetError::RateLimited {
+                retry_after: Some(retry_after),
+                ..
+            } => delay.max(*retry_after),
+            _ => delay,
+        }
+    }
+
+    /// 判断请求在当前策略下是否允许重试（幂等性检查）
+    pub fn allows<P: Serialize + ?Sized>(&self, method: &str, path: &str, params: &P) -> bool {
+        self.max_attempts > 1 && (self.retry_non_idempotent || is_idempotent(method, path, params))
+    }
+}
+
+/// 撤单改单接口：以新订单的 newClientOid 判断能否安全重试
+const CANCEL_REPLACE_PATHS: &[&str] = &[
+    "/api/v2/spot/trade/cancel-replace-order",
+    "/api/v2/spot/trade/batch-cancel-replace-order",
+];
+
+/// 判断请求是否幂等
+///
+/// GET 请求以及非下单类 POST 请求视为幂等；下单、划转等非幂等接口仅在携带 clientOid
+/// （撤单改单接口为 newClientOid；批量接口要求 orderList 中每一项都携带）时视为幂等
+pub fn is_idempotent<P: Serialize + ?Sized>(method: &str, path: &str, params: &P) -> bool {
+    if method == consts::GET || !NON_IDEMPOTENT_PATHS.contains(&path) {
+        return true;
+    }
+    // 撤单改单的 clientOid 指向原订单，重复提交仍会再下一笔新订单
+    let key = if CANCEL_REPLACE_PATHS.contains(&path) {
+        "newClientOid"
+    } else {
+        "clientOid"
+    };
+    match serde_json::to_value(params) {
+        Ok(value) => has_client_oid(&value, key),
+        Err(_) => false,
+    }
+}
+
+/// 请求体是否携带指定的 clientOid 字段
+fn has_client_oid(value: &Value, key: &str) -> bool {
+    let non_empty = |v: &Value| {
+        v.get(key)
+            .and_then(Value::as_str)
+            .is_some_and(|s| !s.is_empty())
+    };
+    if non_empty(value) {
+        return true;
+    }
+    match value.get("orderList").and_then(Value::as_array) {
+        Some(items) => !items.is_empty() && items.iter().all(non_empty),
+        None => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn place_order_requires_client_oid() {
+        let path = "/api/v2/spot/trade/place-order";
+        assert!(is_idempotent(consts::GET, path, &json!({})));
+        assert!(!is_idempotent(
+            consts::POST,
+            path,
+            &json!({"symbol": "BTCUSDT"})
+        ));
+        assert!(!is_idempotent(
+            consts::POST,
+            path,
+            &json!({"clientOid": ""})
+        ));
+        assert!(is_idempotent(
+            consts::POST,
+            path,
+            &json!({"clientOid": "a"})
+        ));
+    }
+
+    #[test]
+    fn batch_requires_client_oid_on_every_item() {
+        let path = "/api/v2/spot/trade/batch-orders";
+        let all = json!({"orderList": [{"clientOid": "a"}, {"clientOid": "b"}]});
+        let some = json!({"orderList": [{"clientOid": "a"}, {}]});
+        assert!(is_idempotent(consts::POST, path, &all));
+        assert!(!is_idempotent(consts::POST, path, &some));
+        assert!(!is_idempotent(
+            consts::POST,
+            path,
+            &json!({"orderList": []})
+        ));
+    }
+
+    #[test]
+    fn cancel_replace_requires_new_client_oid() {
+        let path = "/api/v2/spot/trade/cancel-replace-order";
+        let original_only = json!({"symbol": "BTCUSDT", "clientOid": "old"});
+        let with_new = json!({"symbol": "BTCUSDT", "clientOid": "old", "newClientOid": "new"});
+        assert!(!is_idempotent(consts::POST, path, &original_only));
+        assert!(is_idempotent(consts::POST, path, &with_new));
+    }
+
+    #[test]
+    fn batch_cancel_replace_requires_new_client_oid_on_every_item() {
+        let path = "/api/v2/spot/trade/batch-cancel-replace-order";
+        let all = json!({"orderList": [
+            {"orderId": "1", "newClientOid": "a"},
+            {"clientOid": "old", "newClientOid": "b"}
+        ]});
+        let original_only = json!({"orderList": [
+            {"orderId": "1", "newClientOid": "a"},
+            {"clientOid": "old"}
+        ]});
+        assert!(is_idempotent(consts::POST, path, &all));
+        assert!(!is_idempotent(consts::POST, path, &original_only));
+    }
+
+    #[test]
+    fn server_rate_limit_is_retried() {
+        let err = BitgetError::RateLimited {
+            path: "/p".to_string(),
+            retry_after: Some(Duration::from_secs(3)),
+        };
+        let policy = RetryPolicy {
+            jitter: false,
+            ..RetryPolicy::default()
+        };
+        assert!(err.is_retryable());
+        assert!(policy.should_retry(&err));
+        assert_eq!(policy.delay(1, &err), Duration::from_secs(3));
+    }
+
+    #[test]
+    fn local_rate_limit_is_not_retried() {
+        let err = BitgetError::LocalRateLimited {
+            path: "/p".to_string(),
+            retry_after: Duration::from_millis(100),
+        };
+        assert!(!err.is_retryable());
+        assert!(!err.is_fatal());
+        assert!(!RetryPolicy::default().should_retry(&err));
+    }
+}
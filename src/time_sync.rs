@@ -0,0 +1,323 @@
+//! Bitget 服务器时间与时钟偏差校准
+//!
+//! 提供公共接口 `/api/v2/public/time`，并基于往返时延（RTT）补偿估算本地与服务器的时钟偏差。
+//! 客户端开启 `use_server_time` 后，签名时间戳会自动叠加该偏差，并按固定间隔刷新
+
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicI64, AtomicU32, AtomicU64, Ordering};
+use std::time::{Duration, Instant};
+use tracing::debug;
+
+use crate::client::BitgetClient;
+use crate::consts;
+use crate::exceptions::{BitgetError, Result};
+use crate::rate_limit::EndpointLimit;
+use crate::response::ApiResponse;
+
+/// 服务器时间接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[EndpointLimit::ip("/api/v2/public/time", 20)];
+
+/// 服务器时间接口路径
+pub const SERVER_TIME_PATH: &str = "/api/v2/public/time";
+
+/// 默认时钟偏差刷新间隔
+pub const DEFAULT_REFRESH_INTERVAL: Duration = Duration::from_secs(300);
+
+/// 校准失败后的初始退避时间，连续失败时按 2 的幂增长
+pub const SYNC_RETRY_BACKOFF: Duration = Duration::from_secs(1);
+
+/// 校准失败后的最大退避时间
+pub const MAX_SYNC_RETRY_BACKOFF: Duration = Duration::from_secs(60);
+
+/// 服务器时间响应结构体
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerTimeResp {
+    /// 服务器时间（毫秒时间戳）
+    pub server_time: String,
+}
+
+/// 本地与服务器的时钟偏差估算器
+///
+/// 偏差 = 服务器时间 - (发送时刻 + 接收时刻) / 2，即假设请求与响应的网络耗时对称
+#[derive(Debug)]
+pub struct ClockSkew {
+    /// 时钟偏差（毫秒）
+    offset_ms: AtomicI64,
+    /// 最近一次测得的往返时延（毫秒）
+    rtt_ms: AtomicU64,
+    /// 刷新间隔（毫秒）
+    refresh_interval_ms: AtomicU64,
+    /// 最近一次校准时刻
+    last_sync: Mutex<Option<Instant>>,
+    /// 是否有请求正在校准
+    syncing: AtomicBool,
+    /// 连续校准失败次数
+    failures: AtomicU32,
+    /// 校准失败后，在该时刻之前不再重试
+    retry_at: Mutex<Option<Instant>>,
+}
+
+impl Default for ClockSkew {
+    fn default() -> Self {
+        Self {
+            offset_ms: AtomicI64::new(0),
+            rtt_ms: AtomicU64::new(0),
+            refresh_interval_ms: AtomicU64::new(DEFAULT_REFRESH_INTERVAL.as_millis() as u64),
+            last_sync: Mutex::new(None),
+            syncing: AtomicBool::new(false),
+            failures: AtomicU32::new(0),
+            retry_at: Mutex::new(None),
+        }
+    }
+}
+
+impl ClockSkew {
+    /// 当前时钟偏差（毫秒，服务器时间 - 本地时间）
+    pub fn offset_ms(&self) -> i64 {
+        self.offset_ms.load(Ordering::Relaxed)
+    }
+
+    /// 最近一次校准时的往返时延
+    pub fn rtt(&self) -> Duration {
+        Duration::from_millis(self.rtt_ms.load(Ordering::Relaxed))
+    }
+
+    /// 设置刷新间隔
+    pub fn set_refresh_interval(&self, interval: Duration) {
+        self.refresh_interval_ms
+            .store(interval.as_millis() as u64, Ordering::Relaxed);
+    }
+
+    /// 补偿偏差后的当前毫秒时间戳
+    pub fn now_ms(&self) -> i64 {
+        local_millis() + self.offset_ms()
+    }
+
+    /// 是否需要重新校准（从未校准或超过刷新间隔）
+    pub fn is_stale(&self) -> bool {
+        let interval = Duration::from_millis(self.refresh_interval_ms.load(Ordering::Relaxed));
+        match *self.last_sync.lock().unwrap_or_else(|e| e.into_inner()) {
+            Some(at) => at.elapsed() >= interval,
+            None => true,
+        }
+    }
+
+    /// 标记偏差失效，下一次签名请求前会重新校准（同时清除失败退避）
+    pub fn invalidate(&self) {
+        *self.last_sync.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        *self.retry_at.lock().unwrap_or_else(|e| e.into_inner()) = None;
+    }
+
+    /// 申请校准许可
+    ///
+    /// 偏差未过期、处于失败退避期或已有其他请求在校准时返回 None；
+    /// 否则返回许可，许可释放前其他请求不会再发起校准
+    pub fn begin_sync(&self) -> Option<SyncGuard<'_>> {
+        if !self.is_stale() {
+            return None;
+        }
+        if let Some(at) = *self.retry_at.lock().unwrap_or_else(|e| e.into_inner())
+            && Instant::now() < at
+        {
+            return None;
+        }
+        self.syncing
+            .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+            .ok()?;
+        Some(SyncGuard { skew: self })
+    }
+
+    /// 记录一次校准失败，并设置下次重试前的退避时间
+    ///
+    /// # 返回
+    /// 返回本次退避时间
+    pub fn record_failure(&self) -> Duration {
+        let failures = self.failures.fetch_add(1, Ordering::Relaxed);
+        let backoff = SYNC_RETRY_BACKOFF
+            .saturating_mul(1 << failures.min(16))
+            .min(MAX_SYNC_RETRY_BACKOFF);
+        *self.retry_at.lock().unwrap_or_else(|e| e.into_inner()) = Some(Instant::now() + backoff);
+        backoff
+    }
+
+    /// 根据一次测量结果更新偏差
+    ///
+    /// # 参数
+    /// * `sent_ms` - 本地发送时刻（毫秒）
+    /// * `server_ms` - 服务器返回的时间（毫秒）
+    /// * `received_ms` - 本地接收时刻（毫秒）
+    ///
+    /// # 返回
+    /// 返回新的时钟偏差（毫秒）
+    pub fn update(&self, sent_ms: i64, server_ms: i64, received_ms: i64) -> i64 {
+        let rtt = (received_ms - sent_ms).max(0);
+        let offset = server_ms - (sent_ms + rtt / 2);
+        self.offset_ms.store(offset, Ordering::Relaxed);
+        self.rtt_ms.store(rtt as u64, Ordering::Relaxed);
+        *self.last_sync.lock().unwrap_or_else(|e| e.into_inner()) = Some(Instant::now());
+        *self.retry_at.lock().unwrap_or_else(|e| e.into_inner()) = None;
+        self.failures.store(0, Ordering::Relaxed);
+        debug!("时钟偏差已校准 - 偏差: {}ms, RTT: {}ms", offset, rtt);
+        offset
+    }
+}
+
+/// 校准许可，释放时允许其他请求再次发起校准
+#[derive(Debug)]
+pub struct SyncGuard<'a> {
+    skew: &'a ClockSkew,
+}
+
+impl Drop for SyncGuard<'_> {
+    fn drop(&mut self) {
+        self.skew.syncing.store(false, Ordering::Release);
+    }
+}
+
+impl BitgetClient {
+    /// 查询服务器时间
+    ///
+    /// # 返回
+    /// 返回服务器时间或错误
+    pub fn get_server_time(&self) -> Result<ServerTimeResp> {
+        // 直接发送，避免校准时间时再次触发校准
+        let params: BTreeMap<String, String> = BTreeMap::new();
+        let response = self.send(consts::GET, SERVER_TIME_PATH, &params)?;
+        Ok(ApiResponse::<ServerTimeResp>::from_text(&response.body)?.data)
+    }
+
+    /// 查询服务器时间（异步版本）
+    ///
+    /// # 返回
+    /// 返回服务器时间或错误
+    pub async fn get_server_time_async(&self) -> Result<ServerTimeResp> {
+        // 直接发送，避免校准时间时再次触发校准
+        let params: BTreeMap<String, String> = BTreeMap::new();
+        let response = self
+            .send_async(consts::GET, SERVER_TIME_PATH, &params)
+            .await?;
+        Ok(ApiResponse::<ServerTimeResp>::from_text(&response.body)?.data)
+    }
+
+    /// 测量并更新本地与服务器的时钟偏差
+    ///
+    /// # 返回
+    /// 返回新的时钟偏差（毫秒）或错误
+    pub fn sync_server_time(&self) -> Result<i64> {
+        // 限频等待不计入往返时延，只对 HTTP 往返计时
+        let params: BTreeMap<String, String> = BTreeMap::new();
+        let result = self.throttle(SERVER_TIME_PATH).and_then(|()| {
+            let sent_ms = local_millis();
+            let response = self.dispatch(consts::GET, SERVER_TIME_PATH, &params)?;
+            let received_ms = local_millis();
+            let resp = ApiResponse::<ServerTimeResp>::from_text(&response.body)?.data;
+            Ok((sent_ms, parse_server_time(&resp)?, received_ms))
+        });
+        self.finish_sync(result)
+    }
+
+    /// 测量并更新本地与服务器的时钟偏差（异步版本）
+    ///
+    /// # 返回
+    /// 返回新的时钟偏差（毫秒）或错误
+    pub async fn sync_server_time_async(&self) -> Result<i64> {
+        // 限频等待不计入往返时延，只对 HTTP 往返计时
+        let params: BTreeMap<String, String> = BTreeMap::new();
+        let result = async {
+            self.throttle_async(SERVER_TIME_PATH).await?;
+            let sent_ms = local_millis();
+            let response = self
+                .dispatch_async(consts::GET, SERVER_TIME_PATH, &params)
+                .await?;
+            let received_ms = local_millis();
+            let resp = ApiResponse::<ServerTimeResp>::from_text(&response.body)?.data;
+            Ok((sent_ms, parse_server_time(&resp)?, received_ms))
+        }
+        .await;
+        self.finish_sync(result)
+    }
+
+    /// 根据测量结果更新偏差，失败时记录退避
+    fn finish_sync(&self, result: Result<(i64, i64, i64)>) -> Result<i64> {
+        match result {
+            Ok((sent_ms, server_ms, received_ms)) => {
+                Ok(self.clock_skew().update(sent_ms, server_ms, received_ms))
+            }
+            Err(e) => {
+                let backoff = self.clock_skew().record_failure();
+                debug!("时钟偏差校准失败，{:?} 内不再重试: {}", backoff, e);
+                Err(e)
+            }
+        }
+    }
+}
+
+/// 本地当前毫秒时间戳
+fn local_millis() -> i64 {
+    chrono::Utc::now().timestamp_millis()
+}
+
+/// 解析服务器时间字段
+fn parse_server_time(resp: &ServerTimeResp) -> Result<i64> {
+    resp.server_time.parse::<i64>().map_err(|e| {
+        BitgetError::decode(
+            serde::de::Error::custom(format!("无效的服务器时间: {}", e)),
+            &resp.server_time,
+        )
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn update_compensates_half_rtt() {
+        let skew = ClockSkew::default();
+        assert_eq!(skew.update(1_000, 1_600, 1_200), 500);
+        assert_eq!(skew.rtt(), Duration::from_millis(200));
+        assert!(!skew.is_stale());
+    }
+
+    #[test]
+    fn begin_sync_is_single_flight() {
+        let skew = ClockSkew::default();
+        let guard = skew.begin_sync().expect("从未校准时应取得许可");
+        assert!(skew.begin_sync().is_none());
+        drop(guard);
+        assert!(skew.begin_sync().is_some());
+    }
+
+    #[test]
+    fn failure_backs_off_until_invalidated() {
+        let skew = ClockSkew::default();
+        assert_eq!(skew.record_failure(), SYNC_RETRY_BACKOFF);
+        assert!(skew.begin_sync().is_none());
+        assert_eq!(skew.record_failure(), SYNC_RETRY_BACKOFF * 2);
+
+        skew.invalidate();
+        assert!(skew.begin_sync().is_some());
+    }
+
+    #[test]
+    fn backoff_is_capped() {
+        let skew = ClockSkew::default();
+        for _ in 0..40 {
+            skew.record_failure();
+        }
+        assert_eq!(skew.record_failure(), MAX_SYNC_RETRY_BACKOFF);
+    }
+
+    #[test]
+    fn successful_update_clears_backoff() {
+        let skew = ClockSkew::default();
+        skew.record_failure();
+        skew.update(0, 0, 0);
+        skew.invalidate();
+        assert!(skew.begin_sync().is_some());
+        assert_eq!(skew.record_failure(), SYNC_RETRY_BACKOFF);
+    }
+}
@@ -0,0 +1,339 @@
+//! 按时间窗口拆分历史查询
+//!
+//! Bitget 历史类接口限制单次查询的时间跨度（如 90 天）。本模块把任意 `[from, to)` 区间
+//! 拆分为不超过最大跨度的窗口，在每个窗口内按游标分页，最后去重并按时间升序返回
+
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use std::cmp::Ordering;
+use std::collections::HashSet;
+use std::time::Duration;
+
+use crate::client::BitgetClient;
+use crate::exceptions::{BitgetError, Result};
+use crate::pagination::{CursorItem, Paginator};
+
+/// 90 天，Bitget 多数现货历史接口允许的最大时间跨度
+pub const NINETY_DAYS: Duration = Duration::from_secs(90 * 24 * 60 * 60);
+
+/// 支持设置时间范围的查询参数
+pub trait TimeRangeQuery {
+    /// 设置查询的开始与结束时间（毫秒时间戳，均包含），并清除分页游标
+    fn set_time_range(&mut self, start_ms: i64, end_ms: i64);
+}
+
+/// 时间窗口 `[start, end)`（毫秒时间戳）
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimeWindow {
+    /// 开始时间（包含）
+    pub start: i64,
+    /// 结束时间（不包含）
+    pub end: i64,
+}
+
+/// 将 `[from, to)` 拆分为跨度不超过 `max_span` 的连续窗口
+///
+/// # 参数
+/// * `from` - 开始时间（毫秒时间戳，包含）
+/// * `to` - 结束时间（毫秒时间戳，不包含）
+/// * `max_span` - 单个窗口的最大跨度
+///
+/// # 返回
+/// 返回按时间升序排列的窗口，`from >= to` 时为空；`max_span` 小于 1 毫秒时返回错误
+pub fn split(from: i64, to: i64, max_span: Duration) -> Result<Vec<TimeWindow>> {
+    let span = i64::try_from(max_span.as_millis()).unwrap_or(i64::MAX);
+    if span <= 0 {
+        return Err(BitgetError::InvalidRequest(
+            "时间窗口跨度必须大于 0".to_string(),
+        ));
+    }
+
+    let mut windows = Vec::new();
+    let mut start = from;
+    while start < to {
+        let end = start.saturating_add(span).min(to);
+        windows.push(TimeWindow { start, end });
+        start = end;
+    }
+    Ok(windows)
+}
+
+/// 按记录 ID 去重，并按时间（其次按 ID）升序排列
+///
+/// 没有 ID 的记录无法判断是否重复，全部保留
+///
+/// # 参数
+/// * `items` - 各窗口、各页的记录
+///
+/// # 返回
+/// 返回去重、排序后的记录
+pub fn merge<T: CursorItem>(items: Vec<T>) -> Vec<T> {
+    let mut seen = HashSet::new();
+    let mut merged: Vec<T> = items
+        .into_iter()
+        .filter(|item| match item.cursor_id() {
+            Some(id) => seen.insert(id.to_string()),
+            None => true,
+        })
+        .collect();
+    merged.sort_by(|a, b| {
+        a.timestamp_ms()
+            .cmp(&b.timestamp_ms())
+            .then_with(|| compare_ids(a.cursor_id(), b.cursor_id()))
+    });
+    merged
+}
+
+/// 比较数字字符串形式的 ID（先比长度再比字典序，等价于数值比较）
+fn compare_ids(a: Option<&str>, b: Option<&str>) -> Ordering {
+    match (a, b) {
+        (Some(a), Some(b)) => a.len().cmp(&b.len()).then_with(|| a.cmp(b)),
+        _ => a.cmp(&b),
+    }
+}
+
+impl BitgetClient {
+    /// 查询任意时间区间内的全部记录
+    ///
+    /// 区间按 `max_span` 拆分为多个窗口，窗口内按游标分页，结果去重后按时间升序返回
+    ///
+    /// # 参数
+    /// * `path` - 接口路径
+    /// * `query` - 查询参数（其中的时间范围会被各窗口覆盖）
+    /// * `page_size` - 接口允许的每页最大条数
+    /// * `max_span` - 接口允许的最大时间跨度
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    ///
+    /// # 返回
+    /// 返回全部记录或第一个错误
+    pub fn get_time_range<T, Q>(
+        &self,
+        path: &'static str,
+        query: &Q,
+        page_size: u32,
+        max_span: Duration,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<T>>
+    where
+        T: CursorItem + DeserializeOwned,
+        Q: TimeRangeQuery + Serialize + Clone,
+    {
+        let mut items = Vec::new();
+        for window in split(from, to, max_span)? {
+            let query = window_query(query, window);
+            items.extend(Paginator::new(self, path, &query, page_size)?.collect_all()?);
+        }
+        Ok(merge(within(items, from, to)))
+    }
+
+    /// 查询任意时间区间内的全部记录（异步版本）
+    ///
+    /// # 参数
+    /// * `path` - 接口路径
+    /// * `query` - 查询参数（其中的时间范围会被各窗口覆盖）
+    /// * `page_size` - 接口允许的每页最大条数
+    /// * `max_span` - 接口允许的最大时间跨度
+    /// * `from` - 开始时间（毫秒时间戳，包含）
+    /// * `to` - 结束时间（毫秒时间戳，不包含）
+    ///
+    /// # 返回
+    /// 返回全部记录或第一个错误
+    pub async fn get_time_range_async<T, Q>(
+        &self,
+        path: &'static str,
+        query: &Q,
+        page_size: u32,
+        max_span: Duration,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<T>>
+    where
+        T: CursorItem + DeserializeOwned,
+        Q: TimeRangeQuery + Serialize + Clone,
+    {
+        let mut items = Vec::new();
+        for window in split(from, to, max_span)? {
+            let query = window_query(query, window);
+            items.extend(
+                Paginator::new(self, path, &query, page_size)?
+                    .collect_all_async()
+                    .await?,
+            );
+        }
+        Ok(merge(within(items, from, to)))
+    }
+}
+
+/// 丢弃时间不在 `[from, to)` 内的记录
+fn within<T: CursorItem>(mut items: Vec<T>, from: i64, to: i64) -> Vec<T> {
+    items.retain(|item| item.timestamp_ms().is_none_or(|ts| ts >= from && ts < to));
+    items
+}
+
+/// 复制查询参数并设置为指定窗口（接口的结束时间包含在内，因此取 `end - 1`）
+fn window_query<Q: TimeRangeQuery + Clone>(query: &Q, window: TimeWindow) -> Q {
+    let mut query = query.clone();
+    query.set_time_range(window.start, window.end - 1);
+    query
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY_MS: i64 = 24 * 60 * 60 * 1000;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct Record {
+        id: Option<&'static str>,
+        ts: Option<i64>,
+    }
+
+    impl CursorItem for Record {
+        fn cursor_id(&self) -> Option<&str> {
+            self.id
+        }
+
+        fn timestamp_ms(&self) -> Option<i64> {
+            self.ts
+        }
+    }
+
+    fn record(id: &'static str, ts: i64) -> Record {
+        Record {
+            id: Some(id),
+            ts: Some(ts),
+        }
+    }
+
+    #[derive(Debug, Clone, Default)]
+    struct Query {
+        start: Option<i64>,
+        end: Option<i64>,
+    }
+
+    impl TimeRangeQuery for Query {
+        fn set_time_range(&mut self, start_ms: i64, end_ms: i64) {
+            self.start = Some(start_ms);
+            self.end = Some(end_ms);
+        }
+    }
+
+    #[test]
+    fn split_empty_range() {
+        assert!(split(100, 100, NINETY_DAYS).unwrap().is_empty());
+        assert!(split(200, 100, NINETY_DAYS).unwrap().is_empty());
+    }
+
+    #[test]
+    fn split_exact_multiple_of_span() {
+        let windows = split(0, 3 * DAY_MS, Duration::from_secs(24 * 60 * 60)).unwrap();
+        assert_eq!(
+            windows,
+            [
+                TimeWindow {
+                    start: 0,
+                    end: DAY_MS
+                },
+                TimeWindow {
+                    start: DAY_MS,
+                    end: 2 * DAY_MS
+                },
+                TimeWindow {
+                    start: 2 * DAY_MS,
+                    end: 3 * DAY_MS
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn split_last_window_is_truncated() {
+        let windows = split(0, 250, Duration::from_millis(100)).unwrap();
+        assert_eq!(windows.len(), 3);
+        assert_eq!(
+            windows[2],
+            TimeWindow {
+                start: 200,
+                end: 250
+            }
+        );
+    }
+
+    #[test]
+    fn split_range_shorter_than_span() {
+        let windows = split(10, 20, NINETY_DAYS).unwrap();
+        assert_eq!(windows, [TimeWindow { start: 10, end: 20 }]);
+    }
+
+    #[test]
+    fn split_rejects_zero_span() {
+        assert!(matches!(
+            split(0, 100, Duration::ZERO),
+            Err(BitgetError::InvalidRequest(_))
+        ));
+        // 不足 1 毫秒的跨度同样无效
+        assert!(split(0, 100, Duration::from_micros(500)).is_err());
+    }
+
+    #[test]
+    fn window_query_end_is_inclusive() {
+        let query = window_query(
+            &Query::default(),
+            TimeWindow {
+                start: 100,
+                end: 200,
+            },
+        );
+        assert_eq!((query.start, query.end), (Some(100), Some(199)));
+    }
+
+    #[test]
+    fn within_keeps_half_open_range() {
+        let items = vec![
+            record("1", 99),
+            record("2", 100),
+            record("3", 199),
+            record("4", 200),
+            Record {
+                id: Some("5"),
+                ts: None,
+            },
+        ];
+        let ids: Vec<_> = within(items, 100, 200)
+            .into_iter()
+            .map(|r| r.id.unwrap())
+            .collect();
+        assert_eq!(ids, ["2", "3", "5"]);
+    }
+
+    #[test]
+    fn merge_dedups_by_id() {
+        let merged = merge(vec![record("1", 100), record("2", 200), record("1", 100)]);
+        assert_eq!(merged, [record("1", 100), record("2", 200)]);
+    }
+
+    #[test]
+    fn merge_orders_by_timestamp_then_numeric_id() {
+        let merged = merge(vec![
+            record("10", 200),
+            record("9", 200),
+            record("100", 100),
+            record("2", 300),
+        ]);
+        let ids: Vec<_> = merged.iter().map(|r| r.id.unwrap()).collect();
+        assert_eq!(ids, ["100", "9", "10", "2"]);
+    }
+
+    #[test]
+    fn merge_keeps_records_without_id() {
+        let anonymous = Record {
+            id: None,
+            ts: Some(150),
+        };
+        let merged = merge(vec![record("1", 200), anonymous.clone(), anonymous.clone()]);
+        assert_eq!(merged, [anonymous.clone(), anonymous, record("1", 200)]);
+    }
+}
@@ -1,44 +1,51 @@
 //! Bitget 资金划转相关接口
 //! 示例：POST /api/spot/v1/wallet/transfer
 use crate::client::BitgetClient;
-use anyhow::Result;
+use crate::exceptions::Result;
+use crate::rate_limit::EndpointLimit;
 use serde::{Deserialize, Serialize};
-use std::collections::BTreeMap;
 use tracing::info;
 
+/// 本模块接口的频率限制（每秒请求数）
+pub const RATE_LIMITS: &[EndpointLimit] = &[EndpointLimit::uid("/api/spot/v1/wallet/transfer", 10)];
+
 #[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TransferReq {
     pub from_type: String,
     pub to_type: String,
     pub coin: String,
     pub amount: String,
-    // TODO: 按需补充更多参数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub client_oid: Option<String>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct TransferResp {
     pub transfer_id: Option<String>,
+    #[serde(alias = "clientOrderId")]
+    pub client_oid: Option<String>,
     // TODO: 按需补充更多字段
 }
 
 impl BitgetClient {
     /// 资金划转
-    pub fn transfer(&self, req: &TransferReq) -> Result<String> {
+    pub fn transfer(&self, req: &TransferReq) -> Result<TransferResp> {
+        info!(
+            "资金划转 from={}, to={}, coin={}",
+            req.from_type, req.to_type, req.coin
+        );
+        self.request_typed(crate::consts::POST, "/api/spot/v1/wallet/transfer", req)
+    }
+
+    /// 资金划转（异步版本）
+    pub async fn transfer_async(&self, req: &TransferReq) -> Result<TransferResp> {
         info!(
             "资金划转 from={}, to={}, coin={}",
             req.from_type, req.to_type, req.coin
         );
-        let mut params = BTreeMap::new();
-        params.insert("fromType".to_string(), req.from_type.clone());
-        params.insert("toType".to_string(), req.to_type.clone());
-        params.insert("coin".to_string(), req.coin.clone());
-        params.insert("amount".to_string(), req.amount.clone());
-        // TODO: 其他参数
-        self.request(
-            crate::consts::POST,
-            "/api/spot/v1/wallet/transfer",
-            &params,
-            false,
-        )
+        self.request_typed_async(crate::consts::POST, "/api/spot/v1/wallet/transfer", req)
+            .await
     }
 }
@@ -3,14 +3,31 @@
 //! 该模块提供了 Bitget API 使用的各种工具函数
 //! 包括签名、时间戳、header 构造等
 
-use anyhow::{Result, anyhow};
 use base64::{Engine as _, engine::general_purpose};
 use chrono::Utc;
 use hmac::{Hmac, Mac};
+use percent_encoding::{AsciiSet, NON_ALPHANUMERIC, utf8_percent_encode};
+use rsa::RsaPrivateKey;
+use rsa::pkcs1::DecodeRsaPrivateKey;
+use rsa::pkcs1v15::SigningKey;
+use rsa::pkcs8::DecodePrivateKey;
+use rsa::signature::{SignatureEncoding, Signer};
+use serde::Serialize;
+use serde_json::Value;
 use sha2::Sha256;
 use std::collections::BTreeMap;
+use std::time::Duration;
 
-/// 将参数序列化为 URL 查询字符串（按 key 排序）
+use crate::exceptions::{BitgetError, Result};
+
+/// 查询参数编码集：除 RFC 3986 非保留字符（字母、数字、`-`、`_`、`.`、`~`）外全部百分号编码
+const QUERY_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~');
+
+/// 将参数序列化为 URL 查询字符串（按 key 排序，键值均百分号编码）
 ///
 /// # 参数
 /// * `params` - 要序列化的参数映射
@@ -20,16 +37,62 @@ use std::collections::BTreeMap;
 /// 如果参数为空，返回空字符串
 pub fn parse_params_to_str(params: &BTreeMap<String, String>) -> String {
     if params.is_empty() {
-        return "".to_string();
+        return String::new();
     }
-    let mut s = String::from("?");
-    for (i, (k, v)) in params.iter().enumerate() {
-        if i > 0 {
-            s.push('&');
+    format!("?{}", build_query(params))
+}
+
+/// 将任意可序列化的查询参数转换为字符串键值对
+///
+/// 结构体字段名按其 serde 命名（如 camelCase）输出；`None` 字段被忽略，
+/// 数字与布尔值转为字符串，数组按逗号拼接
+///
+/// # 参数
+/// * `params` - 查询参数（结构体或 map）
+///
+/// # 返回
+/// 返回按 key 排序的参数映射或错误
+pub fn to_query_params<P: Serialize + ?Sized>(params: &P) -> Result<BTreeMap<String, String>> {
+    let value = serde_json::to_value(params)
+        .map_err(|e| BitgetError::InvalidRequest(format!("序列化查询参数失败: {}", e)))?;
+    let object = match value {
+        Value::Object(object) => object,
+        Value::Null => return Ok(BTreeMap::new()),
+        other => {
+            return Err(BitgetError::InvalidRequest(format!(
+                "查询参数必须为对象: {}",
+                other
+            )));
         }
-        s.push_str(&format!("{k}={v}"));
+    };
+
+    let mut query = BTreeMap::new();
+    for (k, v) in object {
+        let v = match v {
+            Value::Null => continue,
+            Value::Array(items) => items
+                .iter()
+                .map(query_value_to_string)
+                .collect::<Result<Vec<_>>>()?
+                .join(","),
+            other => query_value_to_string(&other)?,
+        };
+        query.insert(k, v);
+    }
+    Ok(query)
+}
+
+/// 将单个查询参数值转换为字符串
+fn query_value_to_string(value: &Value) -> Result<String> {
+    match value {
+        Value::String(s) => Ok(s.clone()),
+        Value::Number(n) => Ok(n.to_string()),
+        Value::Bool(b) => Ok(b.to_string()),
+        other => Err(BitgetError::InvalidRequest(format!(
+            "不支持的查询参数值: {}",
+            other
+        ))),
     }
-    s
 }
 
 /// 获取当前 UTC 毫秒时间戳字符串
@@ -40,6 +103,62 @@ pub fn get_timestamp() -> String {
     Utc::now().timestamp_millis().to_string()
 }
 
+/// 生成自定义订单 ID（clientOid）
+///
+/// # 返回
+/// 返回毫秒时间戳加 8 位随机数字组成的字符串，如 "sdk170000000000012345678"
+pub fn generate_client_oid() -> String {
+    format!(
+        "sdk{}{:08}",
+        Utc::now().timestamp_millis(),
+        rand::random::<u32>() % 100_000_000
+    )
+}
+
+/// 反序列化字符串或数字形式的数值字段，统一保存为字符串
+///
+/// # 参数
+/// * `deserializer` - serde 反序列化器
+///
+/// # 返回
+/// 返回数值的字符串形式，字段为 null 时返回 None
+pub(crate) fn string_or_number<'de, D>(
+    deserializer: D,
+) -> std::result::Result<Option<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::Deserialize;
+    match Option::<Value>::deserialize(deserializer)? {
+        None | Some(Value::Null) => Ok(None),
+        Some(Value::String(s)) => Ok(Some(s)),
+        Some(Value::Number(n)) => Ok(Some(n.to_string())),
+        Some(other) => Err(serde::de::Error::custom(format!(
+            "数值字段必须为字符串或数字: {}",
+            other
+        ))),
+    }
+}
+
+/// 将数值字段序列化为字符串（Bitget 请求体中的数值参数均为字符串）
+///
+/// # 参数
+/// * `value` - 字段值
+/// * `serializer` - serde 序列化器
+pub(crate) fn to_string_opt<T, S>(
+    value: &Option<T>,
+    serializer: S,
+) -> std::result::Result<S::Ok, S::Error>
+where
+    T: std::fmt::Display,
+    S: serde::Serializer,
+{
+    match value {
+        Some(v) => serializer.serialize_str(&v.to_string()),
+        None => serializer.serialize_none(),
+    }
+}
+
 /// 构造待签名字符串
 ///
 /// # 参数
@@ -64,12 +183,42 @@ pub fn pre_hash(timestamp: &str, method: &str, request_path: &str, body: &str) -
 /// 返回签名结果字符串或错误
 pub fn sign(pre_hash: &str, secret: &str) -> Result<String> {
     let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes())
-        .map_err(|e| anyhow!("HMAC 初始化失败: {:?}", e))?;
+        .map_err(|e| BitgetError::Signing(format!("HMAC 初始化失败: {:?}", e)))?;
     mac.update(pre_hash.as_bytes());
     let result = mac.finalize().into_bytes();
     Ok(general_purpose::STANDARD.encode(result))
 }
 
+/// 解析 RSA 私钥（支持 PKCS#8 "PRIVATE KEY" 与 PKCS#1 "RSA PRIVATE KEY" 两种 PEM 格式）
+///
+/// # 参数
+/// * `pem` - PEM 格式私钥
+///
+/// # 返回
+/// 返回解析后的私钥或错误
+pub fn parse_rsa_private_key(pem: &str) -> Result<RsaPrivateKey> {
+    let pem = pem.trim();
+    RsaPrivateKey::from_pkcs8_pem(pem)
+        .or_else(|_| RsaPrivateKey::from_pkcs1_pem(pem))
+        .map_err(|e| BitgetError::Signing(format!("RSA 私钥解析失败: {}", e)))
+}
+
+/// SHA256withRSA（PKCS#1 v1.5）签名并 base64 编码
+///
+/// # 参数
+/// * `pre_hash` - 待签名字符串
+/// * `key` - RSA 私钥
+///
+/// # 返回
+/// 返回签名结果字符串或错误
+pub fn sign_rsa(pre_hash: &str, key: &RsaPrivateKey) -> Result<String> {
+    let signing_key = SigningKey::<Sha256>::new(key.clone());
+    let signature = signing_key
+        .try_sign(pre_hash.as_bytes())
+        .map_err(|e| BitgetError::Signing(format!("RSA 签名失败: {}", e)))?;
+    Ok(general_purpose::STANDARD.encode(signature.to_bytes()))
+}
+
 /// 构造请求头
 ///
 /// # 参数
@@ -95,11 +244,136 @@ pub fn get_header(
     ]
 }
 
-/// 构建查询字符串
+/// 是否为携带凭证或签名的请求头（ACCESS-KEY / ACCESS-SIGN / ACCESS-PASSPHRASE）
+///
+/// # 参数
+/// * `name` - 请求头名称
+pub fn is_sensitive_header(name: &reqwest::header::HeaderName) -> bool {
+    matches!(
+        name.as_str(),
+        "access-key" | "access-sign" | "access-passphrase"
+    )
+}
+
+/// 解析响应头中的 Retry-After（秒）
+///
+/// # 参数
+/// * `headers` - 响应头
+///
+/// # 返回
+/// 返回建议等待时间，不存在或无法解析时返回 None
+pub fn retry_after(headers: &reqwest::header::HeaderMap) -> Option<Duration> {
+    headers
+        .get(reqwest::header::RETRY_AFTER)
+        .and_then(|v| v.to_str().ok())
+        .and_then(|v| v.trim().parse::<u64>().ok())
+        .map(Duration::from_secs)
+}
+
+/// 构建查询字符串（不含前导 `?`），URL 与签名原文共用同一编码结果
+///
+/// # 参数
+/// * `params` - 要序列化的参数映射
+///
+/// # 返回
+/// 返回百分号编码后的查询字符串，如 "key1=value1&key2=a%2Cb"
 pub fn build_query(params: &BTreeMap<String, String>) -> String {
     params
         .iter()
-        .map(|(k, v)| format!("{}={}", k, v))
+        .map(|(k, v)| {
+            format!(
+                "{}={}",
+                encode_query_component(k),
+                encode_query_component(v)
+            )
+        })
         .collect::<Vec<_>>()
         .join("&")
 }
+
+/// 对查询参数的键或值做百分号编码
+///
+/// # 参数
+/// * `s` - 原始字符串
+///
+/// # 返回
+/// 返回编码后的字符串，空格编码为 `%20`，`+`、`,`、`/` 等均会被编码
+pub fn encode_query_component(s: &str) -> String {
+    utf8_percent_encode(s, QUERY_ENCODE_SET).to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn params(pairs: &[(&str, &str)]) -> BTreeMap<String, String> {
+        pairs
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn empty_params_produce_no_question_mark() {
+        assert_eq!(parse_params_to_str(&BTreeMap::new()), "");
+        assert_eq!(build_query(&BTreeMap::new()), "");
+    }
+
+    #[test]
+    fn params_are_sorted_by_key() {
+        let query = params(&[("symbol", "BTCUSDT"), ("limit", "10")]);
+        assert_eq!(parse_params_to_str(&query), "?limit=10&symbol=BTCUSDT");
+    }
+
+    #[test]
+    fn special_characters_are_percent_encoded() {
+        assert_eq!(encode_query_component("a b"), "a%20b");
+        assert_eq!(encode_query_component("a,b"), "a%2Cb");
+        assert_eq!(encode_query_component("a+b"), "a%2Bb");
+        assert_eq!(encode_query_component("a/b"), "a%2Fb");
+        assert_eq!(encode_query_component("a=b&c"), "a%3Db%26c");
+    }
+
+    #[test]
+    fn unreserved_characters_are_kept() {
+        assert_eq!(encode_query_component("AZaz09-_.~"), "AZaz09-_.~");
+    }
+
+    #[test]
+    fn non_ascii_is_encoded_as_utf8() {
+        assert_eq!(encode_query_component("币"), "%E5%B8%81");
+        assert_eq!(encode_query_component("é"), "%C3%A9");
+    }
+
+    #[test]
+    fn keys_and_values_are_both_encoded() {
+        let query = params(&[("symbols", "BTCUSDT,ETHUSDT"), ("note key", "1+1/2")]);
+        assert_eq!(
+            build_query(&query),
+            "note%20key=1%2B1%2F2&symbols=BTCUSDT%2CETHUSDT"
+        );
+    }
+
+    #[test]
+    fn to_query_params_drops_none_and_joins_arrays() {
+        #[derive(Serialize)]
+        #[serde(rename_all = "camelCase")]
+        struct Query {
+            symbol: Option<String>,
+            order_ids: Vec<u64>,
+            limit: Option<u32>,
+            is_ok: bool,
+        }
+        let query = to_query_params(&Query {
+            symbol: None,
+            order_ids: vec![1, 2],
+            limit: Some(10),
+            is_ok: true,
+        })
+        .unwrap();
+        assert_eq!(
+            query,
+            params(&[("isOk", "true"), ("limit", "10"), ("orderIds", "1,2")])
+        );
+    }
+}
@@ -1,9 +1,7 @@
 //! Bitget WebSocket API
 //! Bitget WebSocket 行情与推送模块
 
-use anyhow::Result;
 use futures_util::SinkExt;
-use std::collections::HashSet;
 use std::fmt::Debug;
 use std::sync::Arc;
 use tokio::net::TcpStream;
@@ -12,21 +10,43 @@ use tokio_tungstenite::connect_async;
 use tokio_tungstenite::tungstenite::Message as WsMessage;
 use tokio_tungstenite::{MaybeTlsStream, WebSocketStream};
 
+use crate::consts::Environment;
+use crate::exceptions::Result;
+
 #[derive(Debug)]
 pub struct BitgetWebSocketClient {
     stream: Arc<Mutex<WebSocketStream<MaybeTlsStream<TcpStream>>>>,
-    subscriptions: HashSet<String>,
+    environment: Environment,
 }
 
 impl BitgetWebSocketClient {
     pub async fn new(url: &str) -> Result<Self> {
+        Self::connect_url(url, Environment::Live).await
+    }
+
+    /// 连接指定环境的公共频道
+    pub async fn connect_public(environment: Environment) -> Result<Self> {
+        Self::connect_url(environment.ws_public_url(), environment).await
+    }
+
+    /// 连接指定环境的私有频道
+    pub async fn connect_private(environment: Environment) -> Result<Self> {
+        Self::connect_url(environment.ws_private_url(), environment).await
+    }
+
+    async fn connect_url(url: &str, environment: Environment) -> Result<Self> {
         let (ws_stream, _) = connect_async(url).await?;
         Ok(Self {
             stream: Arc::new(Mutex::new(ws_stream)),
-            subscriptions: HashSet::new(),
+            environment,
         })
     }
 
+    /// 当前连接的交易环境
+    pub fn environment(&self) -> Environment {
+        self.environment
+    }
+
     pub async fn subscribe(&mut self, topic: &str) -> Result<()> {
         let msg = serde_json::json!({
             "op": "subscribe",